@@ -2,17 +2,26 @@
 //!
 //! The [`RXConfig`] and [`TXConfig`] structs are used to control the receive and transmit configuration of the CC1101.
 //!
+use crate::decode::reverse_bits;
 use crate::patable::{TX_POWERS_315, TX_POWERS_433, TX_POWERS_868, TX_POWERS_915};
-use crate::{CC1101Error, ConfigError};
+use crate::{CC1101Error, ConfigError, DeviceError};
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::time::Duration;
 
 /// Radio modulation mode
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Modulation {
     /// Frequency Shift Keying (2 Frequencies)
     FSK2 = 0,
     /// Gaussian Shaped Frequency Shift Keying
+    ///
+    /// The Gaussian filter applied to the data is fixed by the hardware and has no configurable parameters.
     GFSK = 1,
     /// On-Off Keying
     OOK = 3,
@@ -22,6 +31,77 @@ pub enum Modulation {
     MSK = 7,
 }
 
+/// Order of the bits within each byte of a packet, as sent on air
+///
+/// The CC1101 always transmits and receives the most significant bit of each byte first. For protocols which send the least significant bit first,
+/// [`CC1101::set_bit_order`](crate::CC1101::set_bit_order) reverses the bits of each byte in software when transmitting and receiving.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BitOrder {
+    /// Most significant bit first, as handled by the radio
+    #[default]
+    MsbFirst,
+    /// Least significant bit first
+    LsbFirst,
+}
+
+impl BitOrder {
+    /// Convert a payload in this bit order to the radio's bit order
+    pub(crate) fn encode<'a>(self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        match self {
+            BitOrder::MsbFirst => Cow::Borrowed(data),
+            BitOrder::LsbFirst => Cow::Owned(reverse_bits(data)),
+        }
+    }
+
+    /// Convert a packet received in the radio's bit order to this bit order
+    pub(crate) fn decode(self, packet: Vec<u8>) -> Vec<u8> {
+        match self {
+            BitOrder::MsbFirst => packet,
+            BitOrder::LsbFirst => reverse_bits(&packet),
+        }
+    }
+}
+
+/// ISM frequency band
+///
+/// TX power tables from [TI DN013](https://www.ti.com/lit/an/swra151a/swra151a.pdf) are specific to a band.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Band {
+    /// 315 MHz
+    Mhz315,
+    /// 433 MHz
+    Mhz433,
+    /// 868 MHz
+    Mhz868,
+    /// 915 MHz
+    Mhz915,
+}
+
+impl Band {
+    /// Get the band a frequency in MHz belongs to
+    ///
+    /// Returns [`None`] if the frequency is not within 1 MHz of 315/433/868/915 MHz.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::Band;
+    /// assert_eq!(Band::from_frequency(433.92), Some(Band::Mhz433));
+    /// assert_eq!(Band::from_frequency(400.0), None);
+    /// ```
+    pub fn from_frequency(frequency: f32) -> Option<Band> {
+        [
+            (Band::Mhz315, 315.0),
+            (Band::Mhz433, 433.0),
+            (Band::Mhz868, 868.0),
+            (Band::Mhz915, 915.0),
+        ]
+        .into_iter()
+        .find(|(_, centre)| (frequency - centre).abs() < 1.0)
+        .map(|(band, _)| band)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum CarrierSense {
     Relative(i8),
@@ -37,7 +117,58 @@ impl fmt::Display for CarrierSense {
     }
 }
 
+/// Thresholds are ordered within each variant, e.g `Relative(6) < Relative(10)` and `Absolute(-7) < Absolute(7)`.
+///
+/// Comparing a [`CarrierSense::Relative`] threshold with a [`CarrierSense::Absolute`] threshold is not meaningful, so `partial_cmp` returns [`None`].
+impl PartialOrd for CarrierSense {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (CarrierSense::Relative(a), CarrierSense::Relative(b)) => a.partial_cmp(b),
+            (CarrierSense::Absolute(a), CarrierSense::Absolute(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+impl CarrierSense {
+    /// Decode the carrier sense threshold from the value of the AGCCTRL1 register
+    ///
+    /// Returns [`None`] if both the relative (`CARRIER_SENSE_REL_THR`) and absolute (`CARRIER_SENSE_ABS_THR`) thresholds are disabled.
+    /// If both are enabled, the relative threshold is returned.
+    pub fn from_agcctrl1(agcctrl1: u8) -> Option<CarrierSense> {
+        match (agcctrl1 >> 4) & 0x03 {
+            1 => return Some(CarrierSense::Relative(6)),
+            2 => return Some(CarrierSense::Relative(10)),
+            3 => return Some(CarrierSense::Relative(14)),
+            _ => (),
+        }
+
+        match agcctrl1 & 0x0F {
+            0x08 => None,
+            // Sign extend the 4-bit threshold
+            threshold => Some(CarrierSense::Absolute(((threshold << 4) as i8) >> 4)),
+        }
+    }
+
+    /// Encode a carrier sense threshold as the `CARRIER_SENSE_REL_THR` and `CARRIER_SENSE_ABS_THR` bits of the AGCCTRL1 register
+    ///
+    /// [`None`] disables both thresholds. The remaining bits of AGCCTRL1 are zero.
+    pub fn to_agcctrl1(carrier_sense: Option<CarrierSense>) -> Result<u8, CC1101Error> {
+        match carrier_sense {
+            Some(CarrierSense::Relative(6)) => Ok(0x18),
+            Some(CarrierSense::Relative(10)) => Ok(0x28),
+            Some(CarrierSense::Relative(14)) => Ok(0x38),
+            Some(CarrierSense::Absolute(threshold @ -7..=7)) => Ok(threshold as u8 & 0x0F),
+            None => Ok(0x08),
+            Some(carrier_sense) => Err(CC1101Error::Config(ConfigError::InvalidCarrierSense {
+                got: carrier_sense,
+            })),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 enum CarrierSenseMode {
     Disabled = 0,
@@ -45,6 +176,102 @@ enum CarrierSenseMode {
     Absolute = 2,
 }
 
+/// Non-fatal issues with a receive configuration, found by [`RXConfig::validate`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigWarning {
+    /// The receive bandwidth is narrower than the estimated bandwidth of the signal (both in kHz)
+    BandwidthTooNarrow { bandwidth: u32, required: f32 },
+    /// The deviation (kHz) is too small relative to the baud rate (kBaud) to reliably distinguish the FSK frequencies
+    DeviationTooLow { deviation: f32, baud_rate: f32 },
+    /// Carrier sense is disabled and the sync word is zero, so packet reception will never be triggered
+    NoRXTrigger,
+}
+
+/// Errors encountered while parsing a configuration from text
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The line at the given line number (starting at 1) is not in the expected format
+    InvalidLine(usize),
+    /// The register name is not a CC1101 configuration register
+    UnknownRegister(String),
+    /// The value could not be parsed
+    InvalidValue(String),
+    /// The input is not the expected length (got the given length)
+    InvalidLength(usize),
+    /// A required field is missing
+    MissingField(&'static str),
+    /// The option name is not recognised
+    UnknownKey(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidLine(line) => write!(f, "invalid line {}", line),
+            ParseError::UnknownRegister(name) => write!(f, "unknown register '{}'", name),
+            ParseError::InvalidValue(value) => write!(f, "invalid value '{}'", value),
+            ParseError::InvalidLength(length) => write!(f, "invalid length {}", length),
+            ParseError::MissingField(field) => write!(f, "missing {}", field),
+            ParseError::UnknownKey(key) => write!(f, "unknown option '{}'", key),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Regulatory region, used by [`TXConfig::check_region`] and [`TXConfig::check_duty_cycle`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Region {
+    /// Europe, 433.05 - 434.79 MHz (ERC Recommendation 70-03)
+    Eu433,
+    /// Europe, 863 - 870 MHz (ERC Recommendation 70-03)
+    Eu868,
+    /// United States, 315 MHz (FCC 15.231)
+    Us315,
+    /// United States, 433 MHz (FCC 15.231)
+    Us433,
+    /// United States, 902 - 928 MHz (FCC 15.249)
+    Us915,
+}
+
+/// Sub-bands for each region - (region, start MHz, end MHz, maximum power dBm, maximum duty cycle)
+///
+/// Where sub-bands overlap, the first match is used. Limits are for a 0 dBi antenna. The US limits are field strength limits converted to EIRP.
+const REGION_BANDS: [(Region, f32, f32, f32, Option<f32>); 9] = [
+    (Region::Eu433, 433.05, 434.79, 10.0, Some(0.1)),
+    (Region::Eu868, 868.0, 868.6, 14.0, Some(0.01)),
+    (Region::Eu868, 868.7, 869.2, 14.0, Some(0.001)),
+    (Region::Eu868, 869.4, 869.65, 27.0, Some(0.1)),
+    (Region::Eu868, 869.7, 870.0, 14.0, Some(0.01)),
+    (Region::Eu868, 863.0, 870.0, 14.0, Some(0.001)),
+    (Region::Us315, 314.0, 316.0, -19.6, None),
+    (Region::Us433, 433.0, 435.0, -14.4, None),
+    (Region::Us915, 902.0, 928.0, -1.2, None),
+];
+
+impl Region {
+    /// Get the maximum power in dBm and duty cycle (0.0 - 1.0) for a frequency in MHz, or [`None`] if it is outside the region's bands
+    fn limits(&self, frequency: f32) -> Option<(f32, Option<f32>)> {
+        REGION_BANDS
+            .iter()
+            .find(|(region, start, end, _, _)| {
+                region == self && (*start..=*end).contains(&frequency)
+            })
+            .map(|(_, _, _, power, duty_cycle)| (*power, *duty_cycle))
+    }
+}
+
+/// Potential regulatory issues with a transmit configuration, found by [`TXConfig::check_region`] and [`TXConfig::check_duty_cycle`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComplianceWarning {
+    /// The frequency (MHz) is outside the bands available in the region
+    OutsideBand { frequency: f32 },
+    /// The TX power exceeds the limit for the band (both in dBm)
+    PowerAboveLimit { tx_power: f32, limit: f32 },
+    /// The duty cycle exceeds the limit for the band (both as a fraction of time, 0.0 - 1.0)
+    DutyCycleAboveLimit { duty_cycle: f32, limit: f32 },
+}
+
 /// Device / driver register types
 #[derive(Copy, Clone)]
 pub enum RegistersType {
@@ -157,7 +384,428 @@ pub struct Registers {
     pub TEST0: u8,
 }
 
+impl Registers {
+    /// Decode the function of the GDO0 pin from IOCFG0
+    pub fn get_gdo0(&self) -> GdoConfig {
+        GdoConfig::from_iocfg(self.IOCFG0)
+    }
+
+    /// Decode the function of the GDO1 pin from IOCFG1
+    pub fn get_gdo1(&self) -> GdoConfig {
+        GdoConfig::from_iocfg(self.IOCFG1)
+    }
+
+    /// Decode the function of the GDO2 pin from IOCFG2
+    pub fn get_gdo2(&self) -> GdoConfig {
+        GdoConfig::from_iocfg(self.IOCFG2)
+    }
+
+    /// Register values in address order (0x00 - 0x2E)
+    ///
+    /// Each field is copied explicitly, so this does not depend on the in-memory layout of [`Registers`]. This is the format read by [`Registers::from_bytes`].
+    pub fn to_bytes(&self) -> [u8; 47] {
+        [
+            self.IOCFG2,
+            self.IOCFG1,
+            self.IOCFG0,
+            self.FIFOTHR,
+            self.SYNC1,
+            self.SYNC0,
+            self.PKTLEN,
+            self.PKTCTRL1,
+            self.PKTCTRL0,
+            self.ADDR,
+            self.CHANNR,
+            self.FSCTRL1,
+            self.FSCTRL0,
+            self.FREQ2,
+            self.FREQ1,
+            self.FREQ0,
+            self.MDMCFG4,
+            self.MDMCFG3,
+            self.MDMCFG2,
+            self.MDMCFG1,
+            self.MDMCFG0,
+            self.DEVIATN,
+            self.MCSM2,
+            self.MCSM1,
+            self.MCSM0,
+            self.FOCCFG,
+            self.BSCFG,
+            self.AGCCTRL2,
+            self.AGCCTRL1,
+            self.AGCCTRL0,
+            self.WOREVT1,
+            self.WOREVT0,
+            self.WORCTRL,
+            self.FREND1,
+            self.FREND0,
+            self.FSCAL3,
+            self.FSCAL2,
+            self.FSCAL1,
+            self.FSCAL0,
+            self.RCCTRL1,
+            self.RCCTRL0,
+            self.FSTEST,
+            self.PTEST,
+            self.AGCTEST,
+            self.TEST2,
+            self.TEST1,
+            self.TEST0,
+        ]
+    }
+
+    /// Construct registers from values in address order (0x00 - 0x2E), as written by [`Registers::to_bytes`]
+    ///
+    /// Returns [`ParseError::InvalidLength`] if `bytes` is not 47 bytes long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::Registers;
+    /// let registers = Registers { SYNC1: 0xD3, ..Default::default() };
+    /// let bytes = registers.to_bytes();
+    /// assert_eq!(bytes[0x04], 0xD3);
+    /// assert_eq!(Registers::from_bytes(&bytes)?.SYNC1, 0xD3);
+    /// # Ok::<(), cc1101_rust::config::ParseError>(())
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Registers, ParseError> {
+        let values = bytes
+            .try_into()
+            .map_err(|_| ParseError::InvalidLength(bytes.len()))?;
+        Ok(Registers::from_array(values))
+    }
+
+    /// Construct registers from values in address order
+    fn from_array(values: [u8; 47]) -> Registers {
+        Registers {
+            IOCFG2: values[0],
+            IOCFG1: values[1],
+            IOCFG0: values[2],
+            FIFOTHR: values[3],
+            SYNC1: values[4],
+            SYNC0: values[5],
+            PKTLEN: values[6],
+            PKTCTRL1: values[7],
+            PKTCTRL0: values[8],
+            ADDR: values[9],
+            CHANNR: values[10],
+            FSCTRL1: values[11],
+            FSCTRL0: values[12],
+            FREQ2: values[13],
+            FREQ1: values[14],
+            FREQ0: values[15],
+            MDMCFG4: values[16],
+            MDMCFG3: values[17],
+            MDMCFG2: values[18],
+            MDMCFG1: values[19],
+            MDMCFG0: values[20],
+            DEVIATN: values[21],
+            MCSM2: values[22],
+            MCSM1: values[23],
+            MCSM0: values[24],
+            FOCCFG: values[25],
+            BSCFG: values[26],
+            AGCCTRL2: values[27],
+            AGCCTRL1: values[28],
+            AGCCTRL0: values[29],
+            WOREVT1: values[30],
+            WOREVT0: values[31],
+            WORCTRL: values[32],
+            FREND1: values[33],
+            FREND0: values[34],
+            FSCAL3: values[35],
+            FSCAL2: values[36],
+            FSCAL1: values[37],
+            FSCAL0: values[38],
+            RCCTRL1: values[39],
+            RCCTRL0: values[40],
+            FSTEST: values[41],
+            PTEST: values[42],
+            AGCTEST: values[43],
+            TEST2: values[44],
+            TEST1: values[45],
+            TEST0: values[46],
+        }
+    }
+
+    /// Compare against another set of registers
+    ///
+    /// Returns the name and the values in `self` and `other` of every register that differs, in address order.
+    /// Wrap the result in [`RegistersDiff`] to print it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{Registers, RegistersDiff};
+    /// let before = Registers { SYNC1: 0xD3, ..Default::default() };
+    /// let after = Registers { SYNC1: 0xAA, ..Default::default() };
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff, vec![("SYNC1", 0xD3, 0xAA)]);
+    /// assert_eq!(RegistersDiff(&diff).to_string(), "SYNC1    0xD3 -> 0xAA\n");
+    /// ```
+    pub fn diff(&self, other: &Registers) -> Vec<(&'static str, u8, u8)> {
+        REGISTER_NAMES
+            .iter()
+            .zip(self.to_bytes().into_iter().zip(other.to_bytes()))
+            .filter(|(_, (a, b))| a != b)
+            .map(|(name, (a, b))| (*name, a, b))
+            .collect()
+    }
+
+    /// Export the registers in the SmartRF Studio `NAME,0xVV` format, one register per line in address order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::Registers;
+    /// let registers = Registers { SYNC1: 0xD3, SYNC0: 0x91, ..Default::default() };
+    /// assert!(registers.to_smartrf().contains("SYNC1,0xD3\nSYNC0,0x91\n"));
+    /// ```
+    pub fn to_smartrf(&self) -> String {
+        REGISTER_NAMES
+            .iter()
+            .zip(self.to_bytes())
+            .map(|(name, value)| format!("{},0x{:02X}\n", name, value))
+            .collect()
+    }
+
+    /// Parse registers from a SmartRF Studio export
+    ///
+    /// Each line contains a register name and a hex value, separated by a comma, whitespace or `=` (e.g. `SYNC1,0xD3`).
+    /// Blank lines and lines starting with `#` or `//` are ignored. Registers not present in the export are set to zero.
+    pub fn from_smartrf(export: &str) -> Result<Registers, ParseError> {
+        let mut values = [0; 47];
+
+        for (index, line) in export.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+
+            let mut fields = line
+                .split(|c: char| c == ',' || c == '=' || c.is_whitespace())
+                .filter(|field| !field.is_empty());
+
+            let (name, value) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(name), Some(value), None) => (name, value),
+                _ => return Err(ParseError::InvalidLine(index + 1)),
+            };
+
+            let address = REGISTER_NAMES
+                .iter()
+                .position(|register| register.eq_ignore_ascii_case(name))
+                .ok_or_else(|| ParseError::UnknownRegister(name.to_string()))?;
+
+            let digits = value
+                .strip_prefix("0x")
+                .or_else(|| value.strip_prefix("0X"))
+                .unwrap_or(value);
+            values[address] = u8::from_str_radix(digits, 16)
+                .map_err(|_| ParseError::InvalidValue(value.to_string()))?;
+        }
+
+        Ok(Registers::from_array(values))
+    }
+}
+
+/// Serialized as a map of register name to value, in address order
+#[cfg(feature = "serde")]
+impl serde::Serialize for Registers {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(REGISTER_NAMES.len()))?;
+        for (name, value) in REGISTER_NAMES.iter().zip(self.to_bytes()) {
+            map.serialize_entry(name, &value)?;
+        }
+        map.end()
+    }
+}
+
+/// Deserialized from a map of register name to value. Registers not present in the map are set to zero.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Registers {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = std::collections::HashMap::<String, u8>::deserialize(deserializer)?;
+
+        let mut values = [0; 47];
+        for (name, value) in map {
+            let address = REGISTER_NAMES
+                .iter()
+                .position(|register| *register == name)
+                .ok_or_else(|| serde::de::Error::unknown_field(&name, &REGISTER_NAMES))?;
+            values[address] = value;
+        }
+
+        Ok(Registers::from_array(values))
+    }
+}
+
+/// GDO pin output signal selection (`GDOx_CFG`)
+///
+/// Covers the common selections from table 41 of the datasheet. Other values are returned as [`GdoFunction::Other`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GdoFunction {
+    /// Asserts when the RX FIFO is filled at or above the threshold
+    RxFifoThreshold,
+    /// Asserts when the RX FIFO is filled at or above the threshold or the end of packet is reached
+    RxFifoThresholdOrEndOfPacket,
+    /// Asserts when the TX FIFO is filled at or above the threshold
+    TxFifoThreshold,
+    /// Asserts when the TX FIFO is full
+    TxFifoFull,
+    /// Asserts when the RX FIFO has overflowed
+    RxFifoOverflow,
+    /// Asserts when the TX FIFO has underflowed
+    TxFifoUnderflow,
+    /// Asserts when a sync word has been sent or received, de-asserts at the end of the packet
+    SyncWord,
+    /// Asserts when a packet has been received with CRC OK
+    PacketReceivedCrcOk,
+    /// Preamble quality reached
+    PreambleQualityReached,
+    /// Clear channel assessment
+    ClearChannelAssessment,
+    /// Lock detector output
+    LockDetector,
+    /// Serial clock
+    SerialClock,
+    /// Serial synchronous data output
+    SerialSynchronousData,
+    /// Serial data output, used in asynchronous serial mode
+    SerialData,
+    /// Carrier sense
+    CarrierSense,
+    /// CRC OK of the last received packet
+    CrcOk,
+    /// Chip ready (active low)
+    ChipReady,
+    /// High impedance (3-state)
+    HighImpedance,
+    /// Hardwired to 0
+    Low,
+    /// Any other selection, containing the raw `GDOx_CFG` value
+    Other(u8),
+}
+
+/// GDO pin configuration decoded from an IOCFG register
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GdoConfig {
+    /// Output signal selection
+    pub function: GdoFunction,
+    /// Output is inverted (`GDOx_INV`)
+    pub inverted: bool,
+}
+
+impl GdoConfig {
+    /// Decode the `GDOx_CFG` and `GDOx_INV` fields of an IOCFG register
+    pub fn from_iocfg(iocfg: u8) -> GdoConfig {
+        let function = match iocfg & 0x3F {
+            0x00 => GdoFunction::RxFifoThreshold,
+            0x01 => GdoFunction::RxFifoThresholdOrEndOfPacket,
+            0x02 => GdoFunction::TxFifoThreshold,
+            0x03 => GdoFunction::TxFifoFull,
+            0x04 => GdoFunction::RxFifoOverflow,
+            0x05 => GdoFunction::TxFifoUnderflow,
+            0x06 => GdoFunction::SyncWord,
+            0x07 => GdoFunction::PacketReceivedCrcOk,
+            0x08 => GdoFunction::PreambleQualityReached,
+            0x09 => GdoFunction::ClearChannelAssessment,
+            0x0A => GdoFunction::LockDetector,
+            0x0B => GdoFunction::SerialClock,
+            0x0C => GdoFunction::SerialSynchronousData,
+            0x0D => GdoFunction::SerialData,
+            0x0E => GdoFunction::CarrierSense,
+            0x0F => GdoFunction::CrcOk,
+            0x29 => GdoFunction::ChipReady,
+            0x2E => GdoFunction::HighImpedance,
+            0x2F => GdoFunction::Low,
+            other => GdoFunction::Other(other),
+        };
+
+        GdoConfig {
+            function,
+            inverted: iocfg & 0x40 != 0,
+        }
+    }
+}
+
+/// Names of the CC1101 configuration registers, in address order (0x00 - 0x2E)
+const REGISTER_NAMES: [&str; 47] = [
+    "IOCFG2", "IOCFG1", "IOCFG0", "FIFOTHR", "SYNC1", "SYNC0", "PKTLEN", "PKTCTRL1", "PKTCTRL0",
+    "ADDR", "CHANNR", "FSCTRL1", "FSCTRL0", "FREQ2", "FREQ1", "FREQ0", "MDMCFG4", "MDMCFG3",
+    "MDMCFG2", "MDMCFG1", "MDMCFG0", "DEVIATN", "MCSM2", "MCSM1", "MCSM0", "FOCCFG", "BSCFG",
+    "AGCCTRL2", "AGCCTRL1", "AGCCTRL0", "WOREVT1", "WOREVT0", "WORCTRL", "FREND1", "FREND0",
+    "FSCAL3", "FSCAL2", "FSCAL1", "FSCAL0", "RCCTRL1", "RCCTRL0", "FSTEST", "PTEST", "AGCTEST",
+    "TEST2", "TEST1", "TEST0",
+];
+
+/// Display helper for the output of [`Registers::diff`], printing one changed register per line
+pub struct RegistersDiff<'a>(pub &'a [(&'static str, u8, u8)]);
+
+impl fmt::Display for RegistersDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, a, b) in self.0 {
+            writeln!(f, "{:<8} 0x{:02X} -> 0x{:02X}", name, a, b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Register values from the device and the driver's transmit and receive configurations
+///
+/// The [`Display`](fmt::Display) implementation prints the three sets of registers side by side, marking registers that differ with `*`.
+#[derive(Debug, Default)]
+pub struct AllRegisters {
+    /// Hardware registers
+    pub device: Registers,
+    /// Driver transmit configuration registers
+    pub tx: Registers,
+    /// Driver receive configuration registers
+    pub rx: Registers,
+}
+
+impl fmt::Display for AllRegisters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<4} {:<8} {:>6} {:>4} {:>4}",
+            "Addr", "Register", "Device", "TX", "RX"
+        )?;
+
+        let device = self.device.to_bytes();
+        let tx = self.tx.to_bytes();
+        let rx = self.rx.to_bytes();
+
+        for (address, name) in REGISTER_NAMES.iter().enumerate() {
+            let (d, t, r) = (device[address], tx[address], rx[address]);
+            let differs = if d == t && t == r { "" } else { " *" };
+            writeln!(
+                f,
+                "0x{:02X} {:<8} {:>6} {:>4} {:>4}{}",
+                address,
+                name,
+                format!("0x{:02X}", d),
+                format!("0x{:02X}", t),
+                format!("0x{:02X}", r),
+                differs
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Packet length in bytes used by [`RXConfig::default`]
+pub const DEFAULT_PACKET_LENGTH: u32 = 1024;
+
 /// Configuration values shared between transmit and receive
+///
+/// With the `serde` feature, deserialized values are checked as per the setters, as the configuration is passed to the driver as-is.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerdeCommonConfig"))]
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq)]
 pub struct CommonConfig {
@@ -190,7 +838,56 @@ impl fmt::Display for CommonConfig {
     }
 }
 
+/// Access to the [`CommonConfig`] of a receive or transmit configuration
+///
+/// Allows functions to operate on the common parameters of either an [`RXConfig`] or a [`TXConfig`].
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::config::{Config, RXConfig, TXConfig, Modulation};
+/// fn describe(config: &impl Config) -> String {
+///     format!("{} MHz {:?}", config.common().get_frequency(), config.common().get_modulation())
+/// }
+///
+/// let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+/// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+/// assert_eq!(describe(&rx_config), describe(&tx_config));
+/// # Ok::<(), cc1101_rust::CC1101Error>(())
+/// ```
+pub trait Config {
+    /// Get the common configuration elements
+    fn common(&self) -> &CommonConfig;
+
+    /// Get a mutable reference to the common configuration elements
+    fn common_mut(&mut self) -> &mut CommonConfig;
+}
+
+impl Config for RXConfig {
+    fn common(&self) -> &CommonConfig {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonConfig {
+        &mut self.common
+    }
+}
+
+impl Config for TXConfig {
+    fn common(&self) -> &CommonConfig {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonConfig {
+        &mut self.common
+    }
+}
+
 /// Configuration values specific to receive
+///
+/// With the `serde` feature, deserialized values are checked as per the setters.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerdeRXConfig"))]
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq)]
 pub struct RXConfig {
@@ -216,7 +913,7 @@ impl Default for RXConfig {
             magn_target: 33,
             carrier_sense_mode: CarrierSenseMode::Relative,
             carrier_sense: 6,
-            packet_length: 1024,
+            packet_length: DEFAULT_PACKET_LENGTH,
         }
     }
 }
@@ -233,13 +930,133 @@ impl fmt::Display for RXConfig {
 }
 
 /// Configuration values specific to transmit
+///
+/// With the `serde` feature, deserialized values are checked as per the setters.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerdeTXConfig"))]
 #[repr(C)]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct TXConfig {
     common: CommonConfig,
     tx_power: u8,
 }
 
+/// Unchecked form of [`CommonConfig`], as serialized
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerdeCommonConfig {
+    frequency: u32,
+    modulation: Modulation,
+    baud_rate_mantissa: u8,
+    baud_rate_exponent: u8,
+    deviation_mantissa: u8,
+    deviation_exponent: u8,
+    sync_word: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerdeCommonConfig> for CommonConfig {
+    type Error = CC1101Error;
+
+    fn try_from(raw: SerdeCommonConfig) -> Result<Self, Self::Error> {
+        let mut config = CommonConfig::default();
+
+        // Check the frequency and baud rate with the setters, but keep the serialized values, as converting back to
+        // MHz/kBaud and re-quantizing may not give exactly the same register values
+        config.set_frequency(CommonConfig::config_to_frequency(raw.frequency))?;
+        config.frequency = raw.frequency;
+
+        config.set_modulation_and_baud_rate(
+            raw.modulation,
+            CommonConfig::config_to_baud_rate(raw.baud_rate_mantissa, raw.baud_rate_exponent),
+        )?;
+        config.baud_rate_mantissa = raw.baud_rate_mantissa;
+        config.baud_rate_exponent = raw.baud_rate_exponent;
+
+        // DEVIATION_M and DEVIATION_E are 3 bits each. Larger values could decode to a valid deviation with a different encoding.
+        let deviation =
+            CommonConfig::config_to_deviation(raw.deviation_mantissa, raw.deviation_exponent);
+        if raw.deviation_mantissa > 7 || raw.deviation_exponent > 7 {
+            return Err(CC1101Error::Config(ConfigError::InvalidDeviation {
+                got: deviation,
+            }));
+        }
+        config.set_deviation(deviation)?;
+        config.set_sync_word(raw.sync_word)?;
+
+        Ok(config)
+    }
+}
+
+/// Unchecked form of [`RXConfig`], as serialized
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerdeRXConfig {
+    common: CommonConfig,
+    bandwidth_mantissa: u8,
+    bandwidth_exponent: u8,
+    max_lna_gain: u8,
+    max_dvga_gain: u8,
+    magn_target: u8,
+    carrier_sense_mode: CarrierSenseMode,
+    carrier_sense: i8,
+    packet_length: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerdeRXConfig> for RXConfig {
+    type Error = CC1101Error;
+
+    fn try_from(raw: SerdeRXConfig) -> Result<Self, Self::Error> {
+        let mut config = RXConfig {
+            common: raw.common,
+            ..RXConfig::default()
+        };
+
+        // CHANBW_M and CHANBW_E are 2 bits each. Larger values could decode to a valid bandwidth with a different encoding.
+        let bandwidth =
+            RXConfig::config_to_bandwidth(raw.bandwidth_mantissa, raw.bandwidth_exponent);
+        if raw.bandwidth_mantissa > 3 || raw.bandwidth_exponent > 3 {
+            return Err(CC1101Error::Config(ConfigError::InvalidBandwidth {
+                got: bandwidth,
+            }));
+        }
+        config.set_bandwidth(bandwidth)?;
+        config.set_max_lna_gain(raw.max_lna_gain)?;
+        config.set_max_dvga_gain(raw.max_dvga_gain)?;
+        config.set_magn_target(raw.magn_target)?;
+        config.set_carrier_sense(match raw.carrier_sense_mode {
+            CarrierSenseMode::Disabled => None,
+            CarrierSenseMode::Relative => Some(CarrierSense::Relative(raw.carrier_sense)),
+            CarrierSenseMode::Absolute => Some(CarrierSense::Absolute(raw.carrier_sense)),
+        })?;
+        config.set_packet_length(raw.packet_length)?;
+
+        Ok(config)
+    }
+}
+
+/// Unchecked form of [`TXConfig`], as serialized
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerdeTXConfig {
+    common: CommonConfig,
+    tx_power: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerdeTXConfig> for TXConfig {
+    type Error = CC1101Error;
+
+    fn try_from(raw: SerdeTXConfig) -> Result<Self, Self::Error> {
+        // Any PATABLE value is allowed, as per TXConfig::new_raw
+        Ok(TXConfig {
+            common: raw.common,
+            tx_power: raw.tx_power,
+        })
+    }
+}
+
 impl fmt::Display for TXConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let tx_power = match Self::get_tx_power(self) {
@@ -253,6 +1070,29 @@ impl fmt::Display for TXConfig {
 
 const XTAL_FREQ: f32 = 26.0;
 
+/// Typical receiver sensitivity from the datasheet (baud rate in kBaud, RX bandwidth in kHz, sensitivity in dBm)
+const SENSITIVITY: [(f32, f32, f32); 4] = [
+    (1.2, 58.0, -112.0),
+    (38.4, 100.0, -104.0),
+    (250.0, 541.0, -95.0),
+    (500.0, 812.0, -86.0),
+];
+
+/// Estimate the bandwidth in kHz occupied by an FSK signal using Carson's rule, `2 x (deviation + baud rate / 2)`
+///
+/// `baud_rate` is in kBaud and `deviation` in kHz. The receive bandwidth should be at least this wide (see [`RXConfig::validate`]).
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::config::carson_bandwidth_khz;
+/// // 38.4 kBaud 2-FSK with 20 kHz deviation occupies ~78 kHz
+/// assert_eq!(carson_bandwidth_khz(38.4, 20.0), 78.4);
+/// ```
+pub fn carson_bandwidth_khz(baud_rate: f32, deviation: f32) -> f32 {
+    2.0 * (deviation + baud_rate / 2.0)
+}
+
 fn round(value: f32, precision: u8) -> f32 {
     let m = 10_f32.powi(precision as i32);
     (value * m).round() / m
@@ -261,6 +1101,9 @@ fn round(value: f32, precision: u8) -> f32 {
 impl CommonConfig {
     /// Create a new CommonConfig
     ///
+    /// `deviation` must be [`None`] for [`Modulation::MSK`], as the frequency deviation is derived from the baud rate. With MSK, the DEVIATN register instead sets the
+    /// fraction of a symbol period used for the phase change, which is left at its default.
+    ///
     /// # Example
     ///
     /// ```
@@ -286,6 +1129,12 @@ impl CommonConfig {
         }
 
         if let Some(deviation) = deviation {
+            if modulation == Modulation::MSK {
+                return Err(CC1101Error::Config(ConfigError::UnsupportedDeviation {
+                    got: deviation,
+                    modulation,
+                }));
+            }
             config.set_deviation(deviation)?;
         }
 
@@ -299,7 +1148,9 @@ impl CommonConfig {
             || (386.99994..=463.9998).contains(&frequency)
             || (778.9999..=928.000000).contains(&frequency))
         {
-            return Err(CC1101Error::Config(ConfigError::InvalidFrequency));
+            return Err(CC1101Error::Config(ConfigError::InvalidFrequency {
+                got: frequency,
+            }));
         }
 
         let f = ((frequency * 65536_f32) / XTAL_FREQ) as u32;
@@ -315,16 +1166,81 @@ impl CommonConfig {
     /// The frequency to receive/transmit on.
     ///
     /// Valid values are 300-348, 387-464 and 779-928 MHz.
+    ///
+    /// The frequency is quantized to a step of approximately 397 Hz, so [`CommonConfig::get_frequency`] may differ slightly from the requested value.
+    /// See [`CommonConfig::frequency_error`].
     pub fn set_frequency(&mut self, frequency: f32) -> Result<(), CC1101Error> {
         self.frequency = CommonConfig::frequency_to_config(frequency)?;
         Ok(())
     }
 
+    /// Get a short, single line summary of the frequency, modulation and baud rate, e.g `433.92MHz OOK 1.0kBd`
+    ///
+    /// Values are rounded for display. Use the [`Display`](fmt::Display) implementation for all settings at full precision.
+    pub fn summary(&self) -> String {
+        format!(
+            "{:.2}MHz {:?} {:.1}kBd",
+            self.get_frequency(),
+            self.modulation,
+            self.get_baud_rate()
+        )
+    }
+
     /// Get the current receive/transmit frequency
     pub fn get_frequency(&self) -> f32 {
         CommonConfig::config_to_frequency(self.frequency)
     }
 
+    /// Get the exact frequency in MHz the radio will use for a requested frequency
+    ///
+    /// This is the value [`CommonConfig::get_frequency`] returns after calling [`CommonConfig::set_frequency`] with the requested frequency.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::CommonConfig;
+    /// assert_eq!(CommonConfig::quantize_frequency(433.0)?, 432.999817);
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn quantize_frequency(frequency: f32) -> Result<f32, CC1101Error> {
+        Ok(CommonConfig::config_to_frequency(
+            CommonConfig::frequency_to_config(frequency)?,
+        ))
+    }
+
+    /// Get the band of the configured frequency
+    ///
+    /// See [`Band::from_frequency`].
+    pub fn band(&self) -> Option<Band> {
+        Band::from_frequency(self.get_frequency())
+    }
+
+    /// Difference in MHz between the configured frequency and a requested frequency
+    ///
+    /// Positive values indicate the configured frequency is above the requested frequency.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{CommonConfig, Modulation};
+    /// let config = CommonConfig::new(433.0, Modulation::OOK, 1.0, None, None)?;
+    /// assert!(config.frequency_error(433.0).abs() < 0.0004);
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn frequency_error(&self, requested: f32) -> f32 {
+        self.get_frequency() - requested
+    }
+
+    /// Range of valid baud rates in kBaud for a modulation
+    pub(crate) fn baud_rate_range(modulation: Modulation) -> RangeInclusive<f32> {
+        match modulation {
+            Modulation::GFSK | Modulation::OOK => 0.599742..=250.0,
+            Modulation::FSK2 => 0.599742..=500.0,
+            Modulation::FSK4 => 0.599742..=300.0,
+            Modulation::MSK => 25.9857..=500.0,
+        }
+    }
+
     /// Convert a baud rate in kBaud to a configuration value.
     ///
     /// Uses the formula from section 12 of the datasheet
@@ -332,29 +1248,35 @@ impl CommonConfig {
         modulation: Modulation,
         baud_rate: f32,
     ) -> Result<(u8, u8), CC1101Error> {
-        let valid_baud_rate = match modulation {
-            Modulation::GFSK | Modulation::OOK => (0.599742..=249.939).contains(&baud_rate),
-            Modulation::FSK2 => (0.599742..=500.0).contains(&baud_rate),
-            Modulation::FSK4 => (0.599742..=299.927).contains(&baud_rate),
-            Modulation::MSK => (25.9857..=499.878).contains(&baud_rate),
-        };
-
-        if !valid_baud_rate {
-            return Err(CC1101Error::Config(ConfigError::InvalidBaudRate));
+        if !CommonConfig::baud_rate_range(modulation).contains(&baud_rate) {
+            return Err(CC1101Error::Config(ConfigError::InvalidBaudRate {
+                got: baud_rate,
+                modulation,
+            }));
         }
 
         let xtal_freq = XTAL_FREQ * 1000000.0;
 
         let r_data = baud_rate * 1000.0;
 
-        let exponent = ((r_data * 2_f32.powi(20)) / xtal_freq).log(2.0).floor();
-        let mantissa =
+        let mut exponent = ((r_data * 2_f32.powi(20)) / xtal_freq).log(2.0).floor();
+        let mut mantissa =
             ((r_data * 2_f32.powi(28) / (xtal_freq * 2_f32.powf(exponent))) - 256_f32).round();
 
-        let mantissa = mantissa as u8;
-        let exponent = exponent as u8;
+        // Rounding up may overflow the mantissa into the next exponent
+        if mantissa >= 256.0 {
+            exponent += 1.0;
+            mantissa = 0.0;
+        }
+
+        if !(0.0..=15.0).contains(&exponent) || mantissa < 0.0 {
+            return Err(CC1101Error::Config(ConfigError::InvalidBaudRate {
+                got: baud_rate,
+                modulation,
+            }));
+        }
 
-        Ok((mantissa, exponent))
+        Ok((mantissa as u8, exponent as u8))
     }
 
     /// Convert a baud rate configuration value to kBaud
@@ -368,6 +1290,61 @@ impl CommonConfig {
         round(r_data / 1000.0, 6)
     }
 
+    /// Convert a channel spacing in kHz to a configuration value
+    ///
+    /// Returns the `(CHANSPC_M, CHANSPC_E)` mantissa and exponent, stored in MDMCFG0 and bits 1:0 of MDMCFG1 respectively.
+    ///
+    /// Uses the formula from section 21 of the datasheet. Valid values are 25.390625 - 405.456543 kHz.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::CommonConfig;
+    /// assert_eq!(CommonConfig::channel_spacing_to_config(199.951172)?, (0xF8, 0x02));
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn channel_spacing_to_config(channel_spacing: f32) -> Result<(u8, u8), CC1101Error> {
+        if !(25.390_625..=405.456_54).contains(&channel_spacing) {
+            return Err(CC1101Error::Config(ConfigError::InvalidChannelSpacing {
+                got: channel_spacing,
+            }));
+        }
+
+        let xtal_freq = XTAL_FREQ * 1000000.0;
+
+        let spacing = channel_spacing * 1000.0 * 2_f32.powi(18) / xtal_freq;
+
+        let mut exponent = spacing.log(2.0).floor() - 8.0;
+        let mut mantissa = (spacing / 2_f32.powf(exponent)).round() - 256.0;
+
+        // Rounding up may overflow the mantissa into the next exponent
+        if mantissa >= 256.0 {
+            exponent += 1.0;
+            mantissa = 0.0;
+        }
+
+        if exponent > 3.0 {
+            return Err(CC1101Error::Config(ConfigError::InvalidChannelSpacing {
+                got: channel_spacing,
+            }));
+        }
+
+        Ok((mantissa as u8, exponent as u8))
+    }
+
+    /// Convert a channel spacing configuration value to kHz
+    ///
+    /// Only the lower two bits of `exponent` are used, as in MDMCFG1.
+    pub fn config_to_channel_spacing(mantissa: u8, exponent: u8) -> f32 {
+        let xtal_freq = XTAL_FREQ * 1000000.0;
+
+        let spacing = (xtal_freq / 2_f32.powi(18))
+            * (256 + mantissa as u32) as f32
+            * 2_f32.powi((exponent & 0x03) as i32);
+
+        spacing / 1000.0
+    }
+
     /// Set the modulation scheme and the baud rate in kBaud
     ///
     /// # Valid Modulation / Baud Rate Values
@@ -380,18 +1357,63 @@ impl CommonConfig {
     /// | [`Modulation::FSK4`] | 0.6 - 300  |
     /// | [`Modulation::MSK`]  | 26 - 500   |
     ///
+    /// Changing to [`Modulation::MSK`] returns [`ConfigError::UnsupportedDeviation`] if a deviation has been set, as per [`CommonConfig::new`].
+    ///
     pub fn set_modulation_and_baud_rate(
         &mut self,
         modulation: Modulation,
         baud_rate: f32,
     ) -> Result<(), CC1101Error> {
         let (mantissa, exponent) = CommonConfig::baud_rate_to_config(modulation, baud_rate)?;
+        self.check_msk_deviation(modulation)?;
         self.modulation = modulation;
         self.baud_rate_mantissa = mantissa;
         self.baud_rate_exponent = exponent;
         Ok(())
     }
 
+    /// Set the modulation scheme, keeping the current baud rate
+    ///
+    /// The current baud rate must be valid for the new modulation, and changing to [`Modulation::MSK`] requires the default deviation, as per [`CommonConfig::set_modulation_and_baud_rate`].
+    pub fn set_modulation(&mut self, modulation: Modulation) -> Result<(), CC1101Error> {
+        let baud_rate = self.get_baud_rate();
+        if !CommonConfig::baud_rate_range(modulation).contains(&baud_rate) {
+            return Err(CC1101Error::Config(ConfigError::InvalidBaudRate {
+                got: baud_rate,
+                modulation,
+            }));
+        }
+        self.check_msk_deviation(modulation)?;
+        self.modulation = modulation;
+        Ok(())
+    }
+
+    /// Check that a deviation has not been set before changing to MSK, where the DEVIATN register has a different meaning
+    ///
+    /// The registers do not record whether a deviation was set, so any deviation other than the default is treated as set.
+    /// Configurations that are already MSK are not checked, so a configuration read back from the driver can still be modified.
+    fn check_msk_deviation(&self, modulation: Modulation) -> Result<(), CC1101Error> {
+        let default = CommonConfig::default();
+        if modulation == Modulation::MSK
+            && self.modulation != Modulation::MSK
+            && (self.deviation_mantissa, self.deviation_exponent)
+                != (default.deviation_mantissa, default.deviation_exponent)
+        {
+            return Err(CC1101Error::Config(ConfigError::UnsupportedDeviation {
+                got: self.get_deviation(),
+                modulation,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Set the baud rate in kBaud, keeping the current modulation
+    ///
+    /// The baud rate must be valid for the current modulation, as per [`CommonConfig::set_modulation_and_baud_rate`].
+    pub fn set_baud_rate(&mut self, baud_rate: f32) -> Result<(), CC1101Error> {
+        self.set_modulation_and_baud_rate(self.modulation, baud_rate)
+    }
+
     /// Get the current modulation
     pub fn get_modulation(&self) -> Modulation {
         self.modulation
@@ -402,13 +1424,20 @@ impl CommonConfig {
         CommonConfig::config_to_baud_rate(self.baud_rate_mantissa, self.baud_rate_exponent)
     }
 
+    /// Difference in kBaud between the configured baud rate and a requested baud rate
+    ///
+    /// Positive values indicate the configured baud rate is above the requested baud rate.
+    pub fn baud_rate_error(&self, requested: f32) -> f32 {
+        self.get_baud_rate() - requested
+    }
+
     /// Convert a deviation configuration value to kHz
     ///
     /// Uses the formula from section 16.1 of the datasheet
     fn config_to_deviation(mantissa: u8, exponent: u8) -> f32 {
         let xtal_freq = XTAL_FREQ * 1000000.0;
         let dev =
-            (xtal_freq / 2_f32.powi(17)) * (mantissa + 8) as f32 * 2_f32.powi(exponent as i32);
+            (xtal_freq / 2_f32.powi(17)) * (mantissa as f32 + 8.0) * 2_f32.powi(exponent as i32);
         round(dev / 1000.0, 6)
     }
 
@@ -422,11 +1451,21 @@ impl CommonConfig {
                 }
             }
         }
-        Err(CC1101Error::Config(ConfigError::InvalidDeviation))
+        Err(CC1101Error::Config(ConfigError::InvalidDeviation {
+            got: deviation,
+        }))
     }
 
     /// Set the frequency deviation in kHz
+    ///
+    /// Returns [`ConfigError::UnsupportedDeviation`] if the modulation is [`Modulation::MSK`], as per [`CommonConfig::new`].
     pub fn set_deviation(&mut self, deviation: f32) -> Result<(), CC1101Error> {
+        if self.modulation == Modulation::MSK {
+            return Err(CC1101Error::Config(ConfigError::UnsupportedDeviation {
+                got: deviation,
+                modulation: self.modulation,
+            }));
+        }
         let (mantissa, exponent) = CommonConfig::deviation_to_config(deviation)?;
         self.deviation_mantissa = mantissa;
         self.deviation_exponent = exponent;
@@ -438,6 +1477,13 @@ impl CommonConfig {
         CommonConfig::config_to_deviation(self.deviation_mantissa, self.deviation_exponent)
     }
 
+    /// Difference in kHz between the configured deviation and a requested deviation
+    ///
+    /// Positive values indicate the configured deviation is above the requested deviation.
+    pub fn deviation_error(&self, requested: f32) -> f32 {
+        self.get_deviation() - requested
+    }
+
     /// Convert a sync word to a configuration value.
     fn sync_word_to_config(sync_word: u32) -> Result<u32, CC1101Error> {
         if sync_word > 0xFFFF {
@@ -445,7 +1491,9 @@ impl CommonConfig {
             let msb = sync_word >> 16;
 
             if lsb != msb {
-                return Err(CC1101Error::Config(ConfigError::InvalidSyncWord));
+                return Err(CC1101Error::Config(ConfigError::InvalidSyncWord {
+                    got: sync_word,
+                }));
             }
         }
         Ok(sync_word)
@@ -455,6 +1503,9 @@ impl CommonConfig {
     ///
     /// Any sync word between 0x0000 and 0xFFFF is allowed. Above 0xFFFF, the high and low 16-bits must be the same (e.g `0x0f0f0f0f`).
     ///
+    /// This is a hardware limitation - the CC1101 only has a 16-bit sync word register (SYNC1/SYNC0). A 32-bit sync word uses the 30/32 sync mode, where the 16-bit word is sent twice.
+    /// Arbitrary 32-bit sync words are not supported by the radio.
+    ///
     /// In RX, the device searches for the specified sync word to begin reception.
     ///
     /// In TX, the sync word is prepended to each packet.
@@ -467,6 +1518,21 @@ impl CommonConfig {
     pub fn get_sync_word(&self) -> u32 {
         self.sync_word
     }
+
+    /// Get the sync word as the high and low bytes programmed into the `SYNC1` and `SYNC0` registers
+    ///
+    /// A 32-bit sync word repeats the same 16-bit word, so gives the same bytes as its low 16 bits.
+    ///
+    /// # Example
+    /// ```
+    /// # use cc1101_rust::config::{CommonConfig, Modulation};
+    /// let config = CommonConfig::new(433.92, Modulation::FSK2, 38.4, Some(20.629883), Some(0xD391))?;
+    /// assert_eq!(config.sync_word_bytes(), (0xD3, 0x91));
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn sync_word_bytes(&self) -> (u8, u8) {
+        ((self.sync_word >> 8) as u8, self.sync_word as u8)
+    }
 }
 
 impl RXConfig {
@@ -474,11 +1540,18 @@ impl RXConfig {
     ///
     /// See [`CommonConfig`] for valid argument values.
     ///
+    /// `packet_length` is the fixed number of bytes received per packet. [`CC1101::receive`](crate::CC1101::receive) allocates a buffer of this size for every packet read,
+    /// so it should be no larger than the protocol requires. [`RXConfig::default`] uses [`DEFAULT_PACKET_LENGTH`].
+    ///
+    /// Packet reception is triggered by the sync word or by carrier sense. If `sync_word` is [`None`] or `0` and `carrier_sense` is [`None`], nothing triggers reception.
+    /// This is not rejected here, but is reported by [`RXConfig::validate`] as [`ConfigWarning::NoRXTrigger`]. Check the result of [`RXConfig::validate`]
+    /// before using a configuration if these arguments come from user input.
+    ///
     /// # Example
     ///
     /// ```
-    /// # use cc1101_rust::config::{RXConfig, Modulation};
-    /// let config = RXConfig::new(433.92, Modulation::OOK, 1.0, 1024, None, None, None, None, None, None, None)?;
+    /// # use cc1101_rust::config::{RXConfig, Modulation, DEFAULT_PACKET_LENGTH};
+    /// let config = RXConfig::new(433.92, Modulation::OOK, 1.0, DEFAULT_PACKET_LENGTH, None, None, None, None, None, None, None)?;
     /// # Ok::<(), cc1101_rust::CC1101Error>(())
     /// ```
     #[allow(clippy::too_many_arguments)]
@@ -499,10 +1572,10 @@ impl RXConfig {
 
         let mut rx_config = RXConfig {
             common,
-            packet_length,
             ..RXConfig::default()
         };
 
+        rx_config.set_packet_length(packet_length)?;
         rx_config.set_carrier_sense(carrier_sense)?;
 
         if let Some(bandwidth) = bandwidth {
@@ -524,6 +1597,29 @@ impl RXConfig {
         Ok(rx_config)
     }
 
+    /// Create a new receive configuration using the frequency, modulation, baud rate, deviation and sync word of a transmit configuration
+    ///
+    /// Receive specific settings use their default values, and can be changed with the setters on [`RXConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{RXConfig, TXConfig, Modulation};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let rx_config = RXConfig::from_tx(&tx_config, 64)?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn from_tx(tx_config: &TXConfig, packet_length: u32) -> Result<RXConfig, CC1101Error> {
+        let mut rx_config = RXConfig {
+            common: tx_config.get_common_config().clone(),
+            ..RXConfig::default()
+        };
+
+        rx_config.set_packet_length(packet_length)?;
+
+        Ok(rx_config)
+    }
+
     /// Get the common configuration elements
     pub fn get_common_config(&self) -> &CommonConfig {
         &self.common
@@ -553,7 +1649,9 @@ impl RXConfig {
                 }
             }
         }
-        Err(CC1101Error::Config(ConfigError::InvalidBandwidth))
+        Err(CC1101Error::Config(ConfigError::InvalidBandwidth {
+            got: bandwidth,
+        }))
     }
 
     /// Set the configured bandwith in KHz
@@ -583,21 +1681,17 @@ impl RXConfig {
         &mut self,
         carrier_sense: Option<CarrierSense>,
     ) -> Result<(), CC1101Error> {
+        CarrierSense::to_agcctrl1(carrier_sense)?;
+
         match carrier_sense {
-            Some(CarrierSense::Relative(carrier_sense)) => match carrier_sense {
-                6 | 10 | 14 => {
-                    self.carrier_sense_mode = CarrierSenseMode::Relative;
-                    self.carrier_sense = carrier_sense;
-                }
-                _ => return Err(CC1101Error::Config(ConfigError::InvalidCarrierSense)),
-            },
-            Some(CarrierSense::Absolute(carrier_sense)) => match carrier_sense {
-                -7..=7 => {
-                    self.carrier_sense_mode = CarrierSenseMode::Absolute;
-                    self.carrier_sense = carrier_sense;
-                }
-                _ => return Err(CC1101Error::Config(ConfigError::InvalidCarrierSense)),
-            },
+            Some(CarrierSense::Relative(carrier_sense)) => {
+                self.carrier_sense_mode = CarrierSenseMode::Relative;
+                self.carrier_sense = carrier_sense;
+            }
+            Some(CarrierSense::Absolute(carrier_sense)) => {
+                self.carrier_sense_mode = CarrierSenseMode::Absolute;
+                self.carrier_sense = carrier_sense;
+            }
             None => {
                 self.carrier_sense_mode = CarrierSenseMode::Disabled;
                 self.carrier_sense = 0;
@@ -620,7 +1714,11 @@ impl RXConfig {
     pub fn set_max_lna_gain(&mut self, max_lna_gain: u8) -> Result<(), CC1101Error> {
         match max_lna_gain {
             0 | 3 | 6 | 7 | 9 | 12 | 15 | 17 => self.max_lna_gain = max_lna_gain,
-            _ => return Err(CC1101Error::Config(ConfigError::InvalidMaxLNAGain)),
+            _ => {
+                return Err(CC1101Error::Config(ConfigError::InvalidMaxLNAGain {
+                    got: max_lna_gain,
+                }))
+            }
         }
         Ok(())
     }
@@ -635,7 +1733,11 @@ impl RXConfig {
     pub fn set_max_dvga_gain(&mut self, max_dvga_gain: u8) -> Result<(), CC1101Error> {
         match max_dvga_gain {
             0 | 6 | 12 | 18 => self.max_dvga_gain = max_dvga_gain,
-            _ => return Err(CC1101Error::Config(ConfigError::InvalidMaxDVGAGain)),
+            _ => {
+                return Err(CC1101Error::Config(ConfigError::InvalidMaxDVGAGain {
+                    got: max_dvga_gain,
+                }))
+            }
         }
         Ok(())
     }
@@ -650,45 +1752,338 @@ impl RXConfig {
     pub fn set_magn_target(&mut self, magn_target: u8) -> Result<(), CC1101Error> {
         match magn_target {
             24 | 27 | 30 | 33 | 36 | 38 | 40 | 42 => self.magn_target = magn_target,
-            _ => return Err(CC1101Error::Config(ConfigError::InvalidMagnTarget)),
+            _ => {
+                return Err(CC1101Error::Config(ConfigError::InvalidMagnTarget {
+                    got: magn_target,
+                }))
+            }
         }
         Ok(())
     }
 
+    /// Sets the target channel filter amplitude to the valid value closest to `magn_target` dB, returning the value used
+    ///
+    /// Values halfway between two valid values are rounded down. Use [`RXConfig::set_magn_target`] to reject values which are not exact.
+    pub fn set_magn_target_nearest(&mut self, magn_target: u8) -> u8 {
+        let nearest = [24, 27, 30, 33, 36, 38, 40, 42]
+            .into_iter()
+            .min_by_key(|valid: &u8| valid.abs_diff(magn_target))
+            .unwrap_or(self.magn_target);
+
+        self.magn_target = nearest;
+        nearest
+    }
+
     /// Get the configured maximum DVGA gain
     pub fn get_magn_target(&self) -> u8 {
         self.magn_target
     }
 
+    /// Check for combinations of settings which are individually valid, but are likely to prevent packets being received
+    ///
+    /// * The RX bandwidth should be at least the bandwidth of the signal. This is estimated using Carson's rule for FSK modulations, `1.5 x baud rate` for MSK and the baud rate for OOK.
+    /// * For FSK modulations, the modulation index (`2 x deviation / baud rate`) should be at least 0.5.
+    /// * Either carrier sense or a sync word is required to trigger packet reception.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{RXConfig, Modulation, ConfigWarning};
+    /// let config = RXConfig::new(433.92, Modulation::FSK2, 100.0, 64, Some(47.607422), Some(0xd391), Some(101), None, None, None, None)?;
+    ///
+    /// if let Err(warnings) = config.validate() {
+    ///     for warning in warnings {
+    ///         println!("{:?}", warning);
+    ///     }
+    /// }
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConfigWarning>> {
+        let mut warnings = vec![];
+
+        let baud_rate = self.common.get_baud_rate();
+        let deviation = self.common.get_deviation();
+        let bandwidth = self.get_bandwith();
+
+        let required = match self.common.get_modulation() {
+            Modulation::FSK2 | Modulation::GFSK | Modulation::FSK4 => {
+                carson_bandwidth_khz(baud_rate, deviation)
+            }
+            Modulation::MSK => 1.5 * baud_rate,
+            Modulation::OOK => baud_rate,
+        };
+
+        if (bandwidth as f32) < required {
+            warnings.push(ConfigWarning::BandwidthTooNarrow {
+                bandwidth,
+                required,
+            });
+        }
+
+        match self.common.get_modulation() {
+            Modulation::FSK2 | Modulation::GFSK | Modulation::FSK4
+                if 2.0 * deviation / baud_rate < 0.5 =>
+            {
+                warnings.push(ConfigWarning::DeviationTooLow {
+                    deviation,
+                    baud_rate,
+                })
+            }
+            _ => (),
+        }
+
+        if self.get_carrier_sense().is_none() && self.common.get_sync_word() == 0 {
+            warnings.push(ConfigWarning::NoRXTrigger);
+        }
+
+        if warnings.is_empty() {
+            Ok(())
+        } else {
+            Err(warnings)
+        }
+    }
+
+    /// Estimate the receiver sensitivity in dBm for this configuration
+    ///
+    /// This is interpolated from the typical sensitivity figures given in the datasheet for 1.2, 38.4, 250 and 500 kBaud, which were measured with 2-FSK/GFSK/MSK.
+    /// The same figures are used for other modulations. If the RX bandwidth is wider than that used for the datasheet figure, the estimate is reduced to account for the additional noise.
+    ///
+    /// This is a rough approximation - actual sensitivity depends on the board, antenna, deviation and environment.
+    pub fn estimated_sensitivity_dbm(&self) -> f32 {
+        let baud_rate = self.common.get_baud_rate();
+
+        // Interpolate between the datasheet figures against log(baud rate), using the nearest figure outside of the table
+        let (mut bandwidth, mut sensitivity) = (SENSITIVITY[0].1, SENSITIVITY[0].2);
+
+        for points in SENSITIVITY.windows(2) {
+            let (baud_rate_0, bandwidth_0, sensitivity_0) = points[0];
+            let (baud_rate_1, bandwidth_1, sensitivity_1) = points[1];
+
+            if baud_rate > baud_rate_0 {
+                let t =
+                    ((baud_rate / baud_rate_0).ln() / (baud_rate_1 / baud_rate_0).ln()).min(1.0);
+                bandwidth = bandwidth_0 + t * (bandwidth_1 - bandwidth_0);
+                sensitivity = sensitivity_0 + t * (sensitivity_1 - sensitivity_0);
+            }
+        }
+
+        let bandwidth_penalty = (10.0 * (self.get_bandwith() as f32 / bandwidth).log10()).max(0.0);
+
+        sensitivity + bandwidth_penalty
+    }
+
     /// Set the length of packets to receive in bytes
-    pub fn set_packet_length(&mut self, packet_length: u32) {
-        self.packet_length = packet_length
+    ///
+    /// The minimum packet length is 1 byte - `0` returns [`ConfigError::InvalidPacketLength`].
+    pub fn set_packet_length(&mut self, packet_length: u32) -> Result<(), CC1101Error> {
+        if packet_length == 0 {
+            return Err(CC1101Error::Config(ConfigError::InvalidPacketLength {
+                got: packet_length,
+            }));
+        }
+        self.packet_length = packet_length;
+        Ok(())
+    }
+
+    /// Set the length of packets to receive in bytes, checking it does not exceed a maximum
+    ///
+    /// `max_packet_size` is typically the value of [`CC1101::get_max_packet_size`](crate::CC1101::get_max_packet_size). The driver rejects receive configurations with a larger packet length.
+    ///
+    /// Returns [`DeviceError::PacketSize`](crate::DeviceError::PacketSize) if the packet length is too large.
+    pub fn set_packet_length_checked(
+        &mut self,
+        packet_length: u32,
+        max_packet_size: u32,
+    ) -> Result<(), CC1101Error> {
+        if packet_length > max_packet_size {
+            return Err(CC1101Error::Device(DeviceError::PacketSize));
+        }
+        self.set_packet_length(packet_length)
+    }
+
+    /// Get a short, single line summary of the configuration, e.g `433.92MHz OOK 1.0kBd BW 101kHz 64B`
+    ///
+    /// This extends [`CommonConfig::summary`] with the bandwidth and packet length.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} BW {}kHz {}B",
+            self.common.summary(),
+            self.get_bandwith(),
+            self.packet_length
+        )
     }
 
     /// Get the configured packet length
     pub fn get_packet_length(&self) -> u32 {
         self.packet_length
     }
+
+    /// Suggest how often to poll for received packets with [`CC1101::receive`](crate::CC1101::receive)
+    ///
+    /// This is the time taken to receive one packet at the configured baud rate. Polling at least this often means at most one new packet
+    /// is waiting in the driver's receive buffer on each poll, so the buffer cannot overflow between polls. The interval is at least 1 ms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{RXConfig, Modulation};
+    /// // 64 bytes at 1 kBaud takes ~512 ms to receive
+    /// let config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+    /// assert!((510..=514).contains(&config.suggested_poll_interval().as_millis()));
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn suggested_poll_interval(&self) -> Duration {
+        let bits = self.packet_length as f64 * 8.0;
+        let baud_rate = self.common.get_baud_rate() as f64 * 1000.0;
+
+        Duration::from_secs_f64(bits / baud_rate).max(Duration::from_millis(1))
+    }
+
+    /// Compare two receive configurations, ignoring the packet length
+    ///
+    /// Returns `true` if both configurations set up the radio identically. Note that the driver still needs to be reconfigured when only the packet length changes,
+    /// as it determines how many bytes the driver reads for each packet - so this is not used by [`CC1101::set_rx_config`](crate::CC1101::set_rx_config).
+    pub fn matches_radio(&self, other: &RXConfig) -> bool {
+        let other = RXConfig {
+            packet_length: self.packet_length,
+            ..other.clone()
+        };
+        *self == other
+    }
 }
 
-impl TXConfig {
-    /// Is a frequency close to a target frequency
-    fn frequency_near(frequency: f32, target_frequency: f32) -> bool {
-        (frequency - target_frequency).abs() < 1.0
+/// Parse a value, returning [`ParseError::InvalidValue`] on failure
+fn parse_value<T: FromStr>(value: &str) -> Result<T, ParseError> {
+    value
+        .parse()
+        .map_err(|_| ParseError::InvalidValue(value.to_string()))
+}
+
+/// Parse a receive configuration from a compact string, for use as a command line argument
+///
+/// The syntax is `frequency:modulation:baud_rate`, followed by any number of optional `:key=value` settings:
+///
+/// | Key    | Setting                                              | Example         |
+/// |--------|------------------------------------------------------|-----------------|
+/// | `len`  | Packet length in bytes (default [`DEFAULT_PACKET_LENGTH`]) | `len=64`  |
+/// | `dev`  | Deviation in kHz                                     | `dev=20.0`      |
+/// | `sync` | Sync word in hex                                     | `sync=d391`     |
+/// | `bw`   | Bandwidth in kHz                                     | `bw=203`        |
+/// | `cs`   | Carrier sense - `rel6`/`rel10`/`rel14`, `abs-7` to `abs7` or `off` | `cs=rel6` |
+/// | `lna`  | Maximum LNA gain in dB                               | `lna=17`        |
+/// | `dvga` | Maximum DVGA gain in dB                              | `dvga=12`       |
+/// | `magn` | Magnitude target in dB                               | `magn=33`       |
+///
+/// Modulation is one of `FSK2`, `GFSK`, `OOK`, `FSK4` or `MSK`, ignoring case. Values are validated as per [`RXConfig::new`].
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::config::{RXConfig, Modulation};
+/// let config: RXConfig = "433.92:OOK:1.0:len=64:cs=rel6".parse()?;
+/// assert_eq!(config.get_packet_length(), 64);
+/// # Ok::<(), cc1101_rust::CC1101Error>(())
+/// ```
+impl FromStr for RXConfig {
+    type Err = CC1101Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.trim().split(':');
+
+        let frequency = fields
+            .next()
+            .filter(|field| !field.is_empty())
+            .ok_or(ParseError::MissingField("frequency"))?;
+        let frequency = parse_value(frequency)?;
+
+        let modulation = fields
+            .next()
+            .ok_or(ParseError::MissingField("modulation"))?;
+        let modulation = match modulation.to_ascii_uppercase().as_str() {
+            "FSK2" => Modulation::FSK2,
+            "GFSK" => Modulation::GFSK,
+            "OOK" => Modulation::OOK,
+            "FSK4" => Modulation::FSK4,
+            "MSK" => Modulation::MSK,
+            _ => return Err(ParseError::InvalidValue(modulation.to_string()).into()),
+        };
+
+        let baud_rate = fields.next().ok_or(ParseError::MissingField("baud rate"))?;
+        let baud_rate = parse_value(baud_rate)?;
+
+        let mut packet_length = DEFAULT_PACKET_LENGTH;
+        let mut deviation = None;
+        let mut sync_word = None;
+        let mut bandwidth = None;
+        let mut carrier_sense = None;
+        let mut max_lna_gain = None;
+        let mut max_dvga_gain = None;
+        let mut magn_target = None;
+
+        for field in fields {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| ParseError::InvalidValue(field.to_string()))?;
+
+            match key {
+                "len" => packet_length = parse_value(value)?,
+                "dev" => deviation = Some(parse_value(value)?),
+                "sync" => {
+                    let digits = value
+                        .strip_prefix("0x")
+                        .or_else(|| value.strip_prefix("0X"))
+                        .unwrap_or(value);
+                    sync_word = Some(
+                        u32::from_str_radix(digits, 16)
+                            .map_err(|_| ParseError::InvalidValue(value.to_string()))?,
+                    );
+                }
+                "bw" => bandwidth = Some(parse_value(value)?),
+                "cs" => {
+                    carrier_sense = if value == "off" {
+                        None
+                    } else if let Some(threshold) = value.strip_prefix("rel") {
+                        Some(CarrierSense::Relative(parse_value(threshold)?))
+                    } else if let Some(threshold) = value.strip_prefix("abs") {
+                        Some(CarrierSense::Absolute(parse_value(threshold)?))
+                    } else {
+                        return Err(ParseError::InvalidValue(value.to_string()).into());
+                    }
+                }
+                "lna" => max_lna_gain = Some(parse_value(value)?),
+                "dvga" => max_dvga_gain = Some(parse_value(value)?),
+                "magn" => magn_target = Some(parse_value(value)?),
+                _ => return Err(ParseError::UnknownKey(key.to_string()).into()),
+            }
+        }
+
+        RXConfig::new(
+            frequency,
+            modulation,
+            baud_rate,
+            packet_length,
+            deviation,
+            sync_word,
+            bandwidth,
+            carrier_sense,
+            max_lna_gain,
+            max_dvga_gain,
+            magn_target,
+        )
     }
+}
 
+impl TXConfig {
     /// Get the appropriate power table based on the provided frequency
     fn get_power_table(frequency: f32) -> Result<&'static [(u8, f32)], CC1101Error> {
-        if Self::frequency_near(frequency, 315.0) {
-            Ok(TX_POWERS_315)
-        } else if Self::frequency_near(frequency, 433.0) {
-            Ok(TX_POWERS_433)
-        } else if Self::frequency_near(frequency, 868.0) {
-            Ok(TX_POWERS_868)
-        } else if Self::frequency_near(frequency, 915.0) {
-            Ok(TX_POWERS_915)
-        } else {
-            Err(CC1101Error::Config(ConfigError::InvalidFrequency))
+        match Band::from_frequency(frequency) {
+            Some(Band::Mhz315) => Ok(TX_POWERS_315),
+            Some(Band::Mhz433) => Ok(TX_POWERS_433),
+            Some(Band::Mhz868) => Ok(TX_POWERS_868),
+            Some(Band::Mhz915) => Ok(TX_POWERS_915),
+            None => Err(CC1101Error::Config(ConfigError::NoPowerTable {
+                got: frequency,
+            })),
         }
     }
 
@@ -698,7 +2093,7 @@ impl TXConfig {
     ///
     /// TX power is specified in dBm. Valid values can be found in [TI DN013](https://www.ti.com/lit/an/swra151a/swra151a.pdf)
     ///
-    /// Frequency must be close to 315/433/868/915Mhz
+    /// Frequency must be close to 315/433/868/915Mhz. Other frequencies return [`ConfigError::NoPowerTable`](crate::ConfigError::NoPowerTable) - use [`TXConfig::new_raw`] instead.
     ///
     /// # Example
     ///
@@ -762,6 +2157,29 @@ impl TXConfig {
         Ok(TXConfig { common, tx_power })
     }
 
+    /// Create a new transmit configuration using the frequency, modulation, baud rate, deviation and sync word of a receive configuration
+    ///
+    /// TX power is specified in dBm, as in [`TXConfig::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{RXConfig, TXConfig, Modulation};
+    /// let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+    /// let tx_config = TXConfig::from_rx(&rx_config, 0.1)?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn from_rx(rx_config: &RXConfig, tx_power: f32) -> Result<TXConfig, CC1101Error> {
+        let mut tx_config = TXConfig {
+            common: rx_config.get_common_config().clone(),
+            ..TXConfig::default()
+        };
+
+        tx_config.set_tx_power(tx_power)?;
+
+        Ok(tx_config)
+    }
+
     /// Lookup a TX power in dBM in the appropriate power table (based on [TI DN013](https://www.ti.com/lit/an/swra151a/swra151a.pdf)).
     ///
     /// Frequency must be within 1MHz of 315/433/868/915Mhz
@@ -774,7 +2192,9 @@ impl TXConfig {
             }
         }
 
-        Err(CC1101Error::Config(ConfigError::InvalidTXPower))
+        Err(CC1101Error::Config(ConfigError::InvalidTXPower {
+            got: tx_power,
+        }))
     }
 
     /// Lookup a TX power PATABLE byte in the appropriate power table (based on [TI DN013](https://www.ti.com/lit/an/swra151a/swra151a.pdf)).
@@ -789,7 +2209,9 @@ impl TXConfig {
             }
         }
 
-        Err(CC1101Error::Config(ConfigError::InvalidTXPower))
+        Err(CC1101Error::Config(ConfigError::InvalidTXPowerRaw {
+            got: tx_power,
+        }))
     }
 
     /// Set the TX power to a value in dBm.
@@ -800,6 +2222,39 @@ impl TXConfig {
         Ok(())
     }
 
+    /// Set the TX power to the closest value in dBm available in the power table, returning the value used
+    ///
+    /// Where two values are equally close, the lower power is used. Use [`TXConfig::set_tx_power`] to reject values which are not in the table.
+    ///
+    /// Configured frequency must be within 1MHz of 315/433/868/915Mhz
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{TXConfig, Modulation};
+    /// let mut config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// assert_eq!(config.set_tx_power_nearest(20.0)?, 9.9);
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn set_tx_power_nearest(&mut self, tx_power: f32) -> Result<f32, CC1101Error> {
+        let power_table = Self::get_power_table(self.common.get_frequency())?;
+
+        let (hex, dbm) = power_table
+            .iter()
+            .min_by(|(_, a), (_, b)| {
+                (a - tx_power)
+                    .abs()
+                    .total_cmp(&(b - tx_power).abs())
+                    .then(a.total_cmp(b))
+            })
+            .ok_or(CC1101Error::Config(ConfigError::InvalidTXPower {
+                got: tx_power,
+            }))?;
+
+        self.tx_power = *hex;
+        Ok(*dbm)
+    }
+
     /// Get the TX power in dBm.
     ///
     /// Configured frequency must be within 1MHz of 315/433/868/915Mhz
@@ -816,6 +2271,75 @@ impl TXConfig {
     pub fn get_tx_power_raw(&self) -> u8 {
         self.tx_power
     }
+
+    /// Check the frequency and TX power against the limits for a regulatory region
+    ///
+    /// This is advisory only, based on a simplified summary of the regulations. It assumes a 0 dBi antenna, and does not cover every
+    /// sub-band or condition (e.g. FCC 15.231 transmission duration limits). Check the regulations that apply to your device.
+    ///
+    /// The TX power is only checked if it is known in dBm (see [`TXConfig::get_tx_power`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cc1101_rust::config::{TXConfig, Modulation, Region, ComplianceWarning};
+    /// let config = TXConfig::new(433.92, Modulation::OOK, 1.0, 9.9, None, None)?;
+    /// assert_eq!(config.check_region(Region::Eu433), Ok(()));
+    /// assert!(config.check_region(Region::Us433).is_err());
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn check_region(&self, region: Region) -> Result<(), Vec<ComplianceWarning>> {
+        let frequency = self.common.get_frequency();
+
+        let (limit, _) = match region.limits(frequency) {
+            Some(limits) => limits,
+            None => return Err(vec![ComplianceWarning::OutsideBand { frequency }]),
+        };
+
+        match self.get_tx_power() {
+            Ok(tx_power) if tx_power > limit => {
+                Err(vec![ComplianceWarning::PowerAboveLimit { tx_power, limit }])
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Estimate the time on air of a packet of `packet_length` bytes
+    ///
+    /// This is calculated from the baud rate and packet length alone, so does not include the preamble or sync word.
+    pub fn air_time(&self, packet_length: usize) -> Duration {
+        Duration::from_secs_f32(packet_length as f32 * 8.0 / (self.common.get_baud_rate() * 1000.0))
+    }
+
+    /// Check the duty cycle of sending a packet of `packet_length` bytes every `interval` against the limit for a regulatory region
+    ///
+    /// The time on air is estimated with [`TXConfig::air_time`]. As with [`TXConfig::check_region`], this is advisory only.
+    pub fn check_duty_cycle(
+        &self,
+        region: Region,
+        packet_length: u32,
+        interval: Duration,
+    ) -> Result<(), Vec<ComplianceWarning>> {
+        let frequency = self.common.get_frequency();
+
+        let limit = match region.limits(frequency) {
+            Some((_, Some(limit))) => limit,
+            Some((_, None)) => return Ok(()),
+            None => return Err(vec![ComplianceWarning::OutsideBand { frequency }]),
+        };
+
+        let duty_cycle =
+            self.air_time(packet_length as usize).as_secs_f32() / interval.as_secs_f32();
+
+        if duty_cycle > limit {
+            Err(vec![ComplianceWarning::DutyCycleAboveLimit {
+                duty_cycle,
+                limit,
+            }])
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -823,6 +2347,47 @@ mod tests {
     #![allow(clippy::excessive_precision)]
     use super::*;
 
+    /// Read the bytes of a field at an offset within a config struct, as seen by the driver
+    fn field_bytes<T, const N: usize>(value: &T, offset: usize) -> [u8; N] {
+        assert!(offset + N <= std::mem::size_of::<T>());
+        unsafe {
+            std::ptr::read_unaligned((value as *const T as *const u8).add(offset) as *const [u8; N])
+        }
+    }
+
+    #[test]
+    fn test_ioctl_layout() -> Result<(), CC1101Error> {
+        use std::mem::{offset_of, size_of};
+
+        assert_eq!(size_of::<CommonConfig>(), 16);
+        assert_eq!(offset_of!(CommonConfig, frequency), 0);
+        assert_eq!(offset_of!(CommonConfig, modulation), 4);
+        assert_eq!(offset_of!(CommonConfig, baud_rate_mantissa), 5);
+        assert_eq!(offset_of!(CommonConfig, deviation_exponent), 8);
+        assert_eq!(offset_of!(CommonConfig, sync_word), 12);
+
+        assert_eq!(size_of::<RXConfig>(), 28);
+        assert_eq!(offset_of!(RXConfig, bandwidth_mantissa), 16);
+        assert_eq!(offset_of!(RXConfig, carrier_sense), 22);
+        assert_eq!(offset_of!(RXConfig, packet_length), 24);
+
+        assert_eq!(size_of::<TXConfig>(), 20);
+        assert_eq!(offset_of!(TXConfig, tx_power), 16);
+
+        assert_eq!(size_of::<Registers>(), 47);
+
+        // Multi-byte fields are passed in native byte order, as expected by the driver
+        let config = CommonConfig::new(433.92, Modulation::FSK2, 1.0, None, Some(0xd391))?;
+        assert_eq!(field_bytes(&config, 0), 0x0010B071_u32.to_ne_bytes());
+        assert_eq!(field_bytes(&config, 12), 0xd391_u32.to_ne_bytes());
+
+        let mut rx_config = RXConfig::default();
+        rx_config.set_packet_length(0x01020304)?;
+        assert_eq!(field_bytes(&rx_config, 24), 0x01020304_u32.to_ne_bytes());
+
+        Ok(())
+    }
+
     #[test]
     fn test_freq() -> Result<(), CC1101Error> {
         assert_eq!(CommonConfig::frequency_to_config(315.0)?, 0x000C1D89);
@@ -908,6 +2473,16 @@ mod tests {
             (0x22, 0x0C)
         );
 
+        // Just below a power of two, the mantissa rounds up into the next exponent rather than being truncated to 0xFF
+        assert_eq!(
+            CommonConfig::baud_rate_to_config(Modulation::FSK2, 1.5868)?,
+            (0x00, 0x06)
+        );
+        assert_eq!(
+            CommonConfig::baud_rate_to_config(Modulation::FSK2, 3.1735)?,
+            (0x00, 0x07)
+        );
+
         assert_eq!(CommonConfig::config_to_baud_rate(0x83, 0x04), 0.599742);
         assert_eq!(CommonConfig::config_to_baud_rate(0x06, 0x0A), 25.98572);
         assert_eq!(CommonConfig::config_to_baud_rate(0x3B, 0x0D), 249.93896);
@@ -922,25 +2497,282 @@ mod tests {
     }
 
     #[test]
-    fn test_deviation() -> Result<(), CC1101Error> {
-        assert_eq!(CommonConfig::deviation_to_config(1.586914)?, (0x00, 0x00));
-        assert_eq!(CommonConfig::deviation_to_config(380.85938)?, (0x07, 0x07));
-        assert_eq!(CommonConfig::config_to_deviation(0x00, 0x00), 1.586914);
-        assert_eq!(CommonConfig::config_to_deviation(0x07, 0x07), 380.859375);
-        assert!(CommonConfig::deviation_to_config(0.0).is_err());
-        assert!(CommonConfig::deviation_to_config(400.0).is_err());
+    fn test_raw_register_extremes() {
+        // Configurations read back from the driver are not checked, so the derived values must not panic on any register contents
+        let common = CommonConfig {
+            frequency: u32::MAX,
+            baud_rate_mantissa: u8::MAX,
+            baud_rate_exponent: u8::MAX,
+            deviation_mantissa: u8::MAX,
+            deviation_exponent: u8::MAX,
+            ..CommonConfig::default()
+        };
+        let rx_config = RXConfig {
+            common: common.clone(),
+            bandwidth_mantissa: u8::MAX,
+            bandwidth_exponent: u8::MAX,
+            packet_length: u32::MAX,
+            ..RXConfig::default()
+        };
+        let tx_config = TXConfig {
+            common,
+            tx_power: u8::MAX,
+        };
 
-        Ok(())
+        rx_config.suggested_poll_interval();
+        rx_config.estimated_sensitivity_dbm();
+        let _ = rx_config.validate();
+        let _ = rx_config.to_string();
+        tx_config.air_time(usize::MAX);
+        let _ = tx_config.check_duty_cycle(Region::Eu868, u32::MAX, Duration::ZERO);
+        let _ = tx_config.to_string();
     }
 
     #[test]
-    fn test_sync_word() -> Result<(), CC1101Error> {
-        CommonConfig::sync_word_to_config(0x00000000)?;
-        CommonConfig::sync_word_to_config(0x0000FFFF)?;
-        CommonConfig::sync_word_to_config(0xFFFFFFFF)?;
+    fn test_baud_rate_limits() -> Result<(), CC1101Error> {
+        // Datasheet data rate limits are the nominal values, which quantize slightly lower
+        for (modulation, min, max) in [
+            (Modulation::OOK, 0.6, 250.0),
+            (Modulation::GFSK, 0.6, 250.0),
+            (Modulation::FSK2, 0.6, 500.0),
+            (Modulation::FSK4, 0.6, 300.0),
+            (Modulation::MSK, 26.0, 500.0),
+        ] {
+            CommonConfig::baud_rate_to_config(modulation, min)?;
+            CommonConfig::baud_rate_to_config(modulation, max)?;
+            assert!(CommonConfig::baud_rate_to_config(modulation, min - 0.1).is_err());
+            assert!(CommonConfig::baud_rate_to_config(modulation, max + 0.1).is_err());
+        }
+
+        // GFSK register values from SmartRF Studio / datasheet (DRATE_M, DRATE_E)
+        assert_eq!(
+            CommonConfig::baud_rate_to_config(Modulation::GFSK, 0.6)?,
+            (0x83, 0x04)
+        );
+        assert_eq!(
+            CommonConfig::baud_rate_to_config(Modulation::GFSK, 1.2)?,
+            (0x83, 0x05)
+        );
+        assert_eq!(
+            CommonConfig::baud_rate_to_config(Modulation::GFSK, 38.4)?,
+            (0x83, 0x0A)
+        );
+        assert_eq!(
+            CommonConfig::baud_rate_to_config(Modulation::GFSK, 250.0)?,
+            (0x3B, 0x0D)
+        );
+        assert_eq!(
+            CommonConfig::baud_rate_to_config(Modulation::GFSK, 249.939)?,
+            (0x3B, 0x0D)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_modulation_and_baud_rate() -> Result<(), CC1101Error> {
+        let mut config = CommonConfig::new(433.92, Modulation::FSK2, 1.0, None, None)?;
+
+        config.set_baud_rate(400.0)?;
+        assert_eq!(config.get_modulation(), Modulation::FSK2);
+        assert_eq!(config.get_baud_rate(), 399.902344);
+
+        // 400 kBaud is too fast for OOK, nothing should change
+        assert!(config.set_modulation(Modulation::OOK).is_err());
+        assert_eq!(config.get_modulation(), Modulation::FSK2);
+
+        config.set_modulation(Modulation::MSK)?;
+        assert_eq!(config.get_modulation(), Modulation::MSK);
+        assert_eq!(config.get_baud_rate(), 399.902344);
+
+        // 1 kBaud is too slow for MSK, nothing should change
+        assert!(config.set_baud_rate(1.0).is_err());
+        assert_eq!(config.get_baud_rate(), 399.902344);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantization_error() -> Result<(), CC1101Error> {
+        let config = CommonConfig::new(433.0, Modulation::FSK2, 115.2, Some(47.607422), None)?;
+
+        assert_eq!(config.frequency_error(433.0), 432.999817 - 433.0);
+        assert_eq!(config.frequency_error(432.999817), 0.0);
+        assert_eq!(config.baud_rate_error(115.2), 115.05126 - 115.2);
+        assert_eq!(config.deviation_error(47.607422), 0.0);
+        assert_eq!(config.deviation_error(50.0), 47.607422 - 50.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_spacing() -> Result<(), CC1101Error> {
+        // Datasheet reset values of MDMCFG1/MDMCFG0
+        assert_eq!(
+            CommonConfig::config_to_channel_spacing(0xF8, 0x02),
+            199.951172
+        );
+        assert_eq!(
+            CommonConfig::channel_spacing_to_config(199.951172)?,
+            (0xF8, 0x02)
+        );
+        assert_eq!(
+            CommonConfig::channel_spacing_to_config(200.0)?,
+            (0xF8, 0x02)
+        );
+
+        assert_eq!(
+            CommonConfig::config_to_channel_spacing(0x00, 0x00),
+            25.390625
+        );
+        assert_eq!(
+            CommonConfig::channel_spacing_to_config(25.390625)?,
+            (0x00, 0x00)
+        );
+        assert_eq!(
+            CommonConfig::config_to_channel_spacing(0xFF, 0x03),
+            405.456543
+        );
+        assert_eq!(
+            CommonConfig::channel_spacing_to_config(405.456543)?,
+            (0xFF, 0x03)
+        );
+
+        // Mantissa rounds up into the next exponent
+        assert_eq!(
+            CommonConfig::channel_spacing_to_config(50.77)?,
+            (0x00, 0x01)
+        );
+
+        assert!(CommonConfig::channel_spacing_to_config(25.0).is_err());
+        assert!(CommonConfig::channel_spacing_to_config(406.0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deviation() -> Result<(), CC1101Error> {
+        assert_eq!(CommonConfig::deviation_to_config(1.586914)?, (0x00, 0x00));
+        assert_eq!(CommonConfig::deviation_to_config(380.85938)?, (0x07, 0x07));
+        assert_eq!(CommonConfig::config_to_deviation(0x00, 0x00), 1.586914);
+        assert_eq!(CommonConfig::config_to_deviation(0x07, 0x07), 380.859375);
+        assert!(CommonConfig::deviation_to_config(0.0).is_err());
+        assert!(CommonConfig::deviation_to_config(400.0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_msk_deviation() -> Result<(), CC1101Error> {
+        CommonConfig::new(433.92, Modulation::MSK, 100.0, None, None)?;
+        CommonConfig::new(433.92, Modulation::FSK2, 100.0, Some(47.607422), None)?;
+
+        assert!(matches!(
+            CommonConfig::new(433.92, Modulation::MSK, 100.0, Some(47.607422), None),
+            Err(CC1101Error::Config(ConfigError::UnsupportedDeviation {
+                modulation: Modulation::MSK,
+                ..
+            }))
+        ));
+        assert!(TXConfig::new(433.92, Modulation::MSK, 100.0, 0.1, Some(47.607422), None).is_err());
+
+        // The setters apply the same check, leaving the config unchanged
+        let mut config = CommonConfig::new(433.92, Modulation::MSK, 100.0, None, None)?;
+        assert!(matches!(
+            config.set_deviation(20.629883),
+            Err(CC1101Error::Config(ConfigError::UnsupportedDeviation {
+                modulation: Modulation::MSK,
+                ..
+            }))
+        ));
+        assert_eq!(config.get_deviation(), 47.607422);
+
+        let mut config = CommonConfig::new(433.92, Modulation::FSK2, 100.0, Some(20.629883), None)?;
+        assert!(matches!(
+            config.set_modulation(Modulation::MSK),
+            Err(CC1101Error::Config(ConfigError::UnsupportedDeviation {
+                modulation: Modulation::MSK,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            config.set_modulation_and_baud_rate(Modulation::MSK, 100.0),
+            Err(CC1101Error::Config(ConfigError::UnsupportedDeviation {
+                modulation: Modulation::MSK,
+                ..
+            }))
+        ));
+        assert_eq!(config.get_modulation(), Modulation::FSK2);
+
+        // With the default deviation, changing to MSK is allowed
+        let mut config = CommonConfig::new(433.92, Modulation::FSK2, 100.0, None, None)?;
+        config.set_modulation(Modulation::MSK)?;
+        config.set_modulation_and_baud_rate(Modulation::FSK2, 100.0)?;
+        config.set_modulation_and_baud_rate(Modulation::MSK, 100.0)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantize_frequency() -> Result<(), CC1101Error> {
+        assert_eq!(CommonConfig::quantize_frequency(433.0)?, 432.999817);
+        assert_eq!(CommonConfig::quantize_frequency(915.0)?, 915.000000);
+
+        let mut config = CommonConfig::default();
+        config.set_frequency(868.3)?;
+        assert_eq!(
+            CommonConfig::quantize_frequency(868.3)?,
+            config.get_frequency()
+        );
+
+        assert!(CommonConfig::quantize_frequency(999.0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_band() -> Result<(), CC1101Error> {
+        assert_eq!(Band::from_frequency(315.0), Some(Band::Mhz315));
+        assert_eq!(Band::from_frequency(433.92), Some(Band::Mhz433));
+        assert_eq!(Band::from_frequency(867.5), Some(Band::Mhz868));
+        assert_eq!(Band::from_frequency(915.9), Some(Band::Mhz915));
+        assert_eq!(Band::from_frequency(434.0), None);
+        assert_eq!(Band::from_frequency(900.0), None);
+
+        let config = CommonConfig::new(868.3, Modulation::OOK, 1.0, None, None)?;
+        assert_eq!(config.band(), Some(Band::Mhz868));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_power_table() {
+        assert!(matches!(
+            TXConfig::new(434.5, Modulation::OOK, 1.0, 0.1, None, None),
+            Err(CC1101Error::Config(ConfigError::NoPowerTable { .. }))
+        ));
+        assert!(matches!(
+            TXConfig::new(950.0, Modulation::OOK, 1.0, 0.1, None, None),
+            Err(CC1101Error::Config(ConfigError::InvalidFrequency { .. }))
+        ));
+        assert!(TXConfig::new_raw(434.5, Modulation::OOK, 1.0, 0x60, None, None).is_ok());
+    }
+
+    #[test]
+    fn test_sync_word() -> Result<(), CC1101Error> {
+        CommonConfig::sync_word_to_config(0x00000000)?;
+        CommonConfig::sync_word_to_config(0x0000FFFF)?;
+        CommonConfig::sync_word_to_config(0xFFFFFFFF)?;
 
         assert!(CommonConfig::sync_word_to_config(0xFFFF0000).is_err());
         assert!(CommonConfig::sync_word_to_config(0xAAAABBBB).is_err());
+
+        let mut config = CommonConfig::default();
+        assert_eq!(config.sync_word_bytes(), (0x00, 0x00));
+        config.set_sync_word(0xD391)?;
+        assert_eq!(config.sync_word_bytes(), (0xD3, 0x91));
+        config.set_sync_word(0x0F1E0F1E)?;
+        assert_eq!(config.sync_word_bytes(), (0x0F, 0x1E));
         Ok(())
     }
 
@@ -958,6 +2790,683 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_carrier_sense_agcctrl1() -> Result<(), CC1101Error> {
+        let mut carrier_senses = vec![
+            None,
+            Some(CarrierSense::Relative(6)),
+            Some(CarrierSense::Relative(10)),
+            Some(CarrierSense::Relative(14)),
+        ];
+        carrier_senses.extend((-7..=7).map(|t| Some(CarrierSense::Absolute(t))));
+
+        for carrier_sense in carrier_senses {
+            let agcctrl1 = CarrierSense::to_agcctrl1(carrier_sense)?;
+            assert_eq!(CarrierSense::from_agcctrl1(agcctrl1), carrier_sense);
+        }
+
+        assert_eq!(
+            CarrierSense::to_agcctrl1(Some(CarrierSense::Absolute(-7)))?,
+            0x09
+        );
+        assert_eq!(
+            CarrierSense::from_agcctrl1(0x40),
+            Some(CarrierSense::Absolute(0))
+        );
+        assert_eq!(CarrierSense::from_agcctrl1(0x48), None);
+        assert_eq!(
+            CarrierSense::from_agcctrl1(0x5F),
+            Some(CarrierSense::Relative(6))
+        );
+
+        assert!(CarrierSense::to_agcctrl1(Some(CarrierSense::Relative(7))).is_err());
+        assert!(CarrierSense::to_agcctrl1(Some(CarrierSense::Absolute(-8))).is_err());
+        assert!(CarrierSense::to_agcctrl1(Some(CarrierSense::Absolute(8))).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_carrier_sense_ordering() {
+        assert!(CarrierSense::Relative(6) < CarrierSense::Relative(10));
+        assert!(CarrierSense::Relative(14) > CarrierSense::Relative(10));
+        assert!(CarrierSense::Absolute(-7) < CarrierSense::Absolute(7));
+        assert!(CarrierSense::Absolute(0) >= CarrierSense::Absolute(0));
+
+        assert_eq!(
+            CarrierSense::Relative(6).partial_cmp(&CarrierSense::Absolute(6)),
+            None
+        );
+        assert_eq!(
+            CarrierSense::Absolute(-7).partial_cmp(&CarrierSense::Relative(14)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_packet_length_checked() -> Result<(), CC1101Error> {
+        let mut config = RXConfig::default();
+
+        config.set_packet_length_checked(64, 64)?;
+        assert_eq!(config.get_packet_length(), 64);
+
+        assert!(matches!(
+            config.set_packet_length_checked(65, 64),
+            Err(CC1101Error::Device(DeviceError::PacketSize))
+        ));
+        assert_eq!(config.get_packet_length(), 64);
+
+        assert!(matches!(
+            config.set_packet_length_checked(0, 64),
+            Err(CC1101Error::Config(ConfigError::InvalidPacketLength {
+                got: 0
+            }))
+        ));
+        assert!(matches!(
+            config.set_packet_length(0),
+            Err(CC1101Error::Config(ConfigError::InvalidPacketLength {
+                got: 0
+            }))
+        ));
+        assert_eq!(config.get_packet_length(), 64);
+        assert!(matches!(
+            RXConfig::new(
+                433.92,
+                Modulation::OOK,
+                1.0,
+                0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Err(CC1101Error::Config(ConfigError::InvalidPacketLength {
+                got: 0
+            }))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gdo_config() {
+        // Datasheet reset values
+        let registers = Registers {
+            IOCFG2: 0x29,
+            IOCFG1: 0x2E,
+            IOCFG0: 0x3F,
+            ..Registers::default()
+        };
+
+        assert_eq!(
+            registers.get_gdo2(),
+            GdoConfig {
+                function: GdoFunction::ChipReady,
+                inverted: false
+            }
+        );
+        assert_eq!(registers.get_gdo1().function, GdoFunction::HighImpedance);
+        assert_eq!(registers.get_gdo0().function, GdoFunction::Other(0x3F));
+
+        assert_eq!(
+            GdoConfig::from_iocfg(0x46),
+            GdoConfig {
+                function: GdoFunction::SyncWord,
+                inverted: true
+            }
+        );
+        // TEMP_SENSOR_ENABLE / GDO_DS in bit 7 are ignored
+        assert_eq!(
+            GdoConfig::from_iocfg(0x8E).function,
+            GdoFunction::CarrierSense
+        );
+    }
+
+    #[test]
+    fn test_all_registers_display() {
+        let mut registers = AllRegisters::default();
+        registers.device.IOCFG2 = 0x29;
+        registers.tx.IOCFG2 = 0x29;
+        registers.rx.IOCFG2 = 0x29;
+        registers.rx.TEST0 = 0x0B;
+
+        let output = registers.to_string();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 48);
+        assert_eq!(lines[0], "Addr Register Device   TX   RX");
+        assert_eq!(lines[1], "0x00 IOCFG2     0x29 0x29 0x29");
+        assert_eq!(lines[2], "0x01 IOCFG1     0x00 0x00 0x00");
+        assert_eq!(lines[47], "0x2E TEST0      0x00 0x00 0x0B *");
+    }
+
+    #[test]
+    fn test_registers_diff() {
+        let a = Registers {
+            IOCFG2: 0x29,
+            SYNC1: 0xD3,
+            TEST0: 0x0B,
+            ..Default::default()
+        };
+        let b = Registers {
+            IOCFG2: 0x29,
+            SYNC1: 0x00,
+            TEST0: 0x09,
+            ..Default::default()
+        };
+
+        assert_eq!(a.diff(&a), vec![]);
+
+        let diff = a.diff(&b);
+        assert_eq!(diff, vec![("SYNC1", 0xD3, 0x00), ("TEST0", 0x0B, 0x09)]);
+        assert_eq!(
+            RegistersDiff(&diff).to_string(),
+            "SYNC1    0xD3 -> 0x00\nTEST0    0x0B -> 0x09\n"
+        );
+        assert_eq!(RegistersDiff(&[]).to_string(), "");
+    }
+
+    #[test]
+    fn test_rx_config_from_str() -> Result<(), CC1101Error> {
+        let config: RXConfig = "433.92:OOK:1.0".parse()?;
+        let expected = RXConfig::new(
+            433.92,
+            Modulation::OOK,
+            1.0,
+            DEFAULT_PACKET_LENGTH,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        assert_eq!(config, expected);
+
+        let config: RXConfig =
+            "868.3:gfsk:38.4:len=64:dev=47.607422:sync=0xd391:bw=203:cs=abs-3:lna=17:dvga=12:magn=33"
+                .parse()?;
+        let expected = RXConfig::new(
+            868.3,
+            Modulation::GFSK,
+            38.4,
+            64,
+            Some(47.607422),
+            Some(0xD391),
+            Some(203),
+            Some(CarrierSense::Absolute(-3)),
+            Some(17),
+            Some(12),
+            Some(33),
+        )?;
+        assert_eq!(config, expected);
+
+        let config: RXConfig = "433.92:OOK:1.0:cs=rel10".parse()?;
+        assert_eq!(config.get_carrier_sense(), Some(CarrierSense::Relative(10)));
+
+        let parse_error = |s: &str| match s.parse::<RXConfig>() {
+            Err(CC1101Error::Parse(e)) => Some(e),
+            _ => None,
+        };
+
+        assert_eq!(parse_error(""), Some(ParseError::MissingField("frequency")));
+        assert_eq!(
+            parse_error("433.92"),
+            Some(ParseError::MissingField("modulation"))
+        );
+        assert_eq!(
+            parse_error("433.92:OOK"),
+            Some(ParseError::MissingField("baud rate"))
+        );
+        assert_eq!(
+            parse_error("433.92:AM:1.0"),
+            Some(ParseError::InvalidValue("AM".to_string()))
+        );
+        assert_eq!(
+            parse_error("433.92:OOK:fast"),
+            Some(ParseError::InvalidValue("fast".to_string()))
+        );
+        assert_eq!(
+            parse_error("433.92:OOK:1.0:len"),
+            Some(ParseError::InvalidValue("len".to_string()))
+        );
+        assert_eq!(
+            parse_error("433.92:OOK:1.0:cs=high"),
+            Some(ParseError::InvalidValue("high".to_string()))
+        );
+        assert_eq!(
+            parse_error("433.92:OOK:1.0:foo=1"),
+            Some(ParseError::UnknownKey("foo".to_string()))
+        );
+
+        assert!(matches!(
+            "1000.0:OOK:1.0".parse::<RXConfig>(),
+            Err(CC1101Error::Config(ConfigError::InvalidFrequency { .. }))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_magn_target_nearest() {
+        let mut config = RXConfig::default();
+
+        assert_eq!(config.set_magn_target_nearest(35), 36);
+        assert_eq!(config.get_magn_target(), 36);
+        assert_eq!(config.set_magn_target_nearest(33), 33);
+        assert_eq!(config.set_magn_target_nearest(39), 38);
+        assert_eq!(config.set_magn_target_nearest(0), 24);
+        assert_eq!(config.set_magn_target_nearest(255), 42);
+        assert_eq!(config.get_magn_target(), 42);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_registers_serde() {
+        let registers = Registers {
+            IOCFG2: 0x29,
+            SYNC1: 0xD3,
+            TEST0: 0x0B,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&registers).unwrap();
+        assert!(json.starts_with(r#"{"IOCFG2":41,"IOCFG1":0,"IOCFG0":0,"FIFOTHR":0,"SYNC1":211,"#));
+        assert!(json.ends_with(r#""TEST0":11}"#));
+
+        let parsed: Registers = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.to_bytes(), registers.to_bytes());
+
+        let parsed: Registers = serde_json::from_str(r#"{"SYNC0":145}"#).unwrap();
+        assert_eq!(parsed.SYNC0, 0x91);
+        assert_eq!(parsed.SYNC1, 0x00);
+
+        assert!(serde_json::from_str::<Registers>(r#"{"FOO":1}"#).is_err());
+        assert!(serde_json::from_str::<Registers>(r#"{"SYNC1":256}"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_serde() -> Result<(), CC1101Error> {
+        let rx_config = RXConfig::new(
+            868.3,
+            Modulation::FSK2,
+            17.241,
+            5,
+            Some(88.867_19),
+            Some(0x2DD4),
+            Some(203),
+            Some(CarrierSense::Absolute(-3)),
+            Some(6),
+            Some(12),
+            Some(38),
+        )?;
+        let json = serde_json::to_value(&rx_config).unwrap();
+        assert_eq!(
+            serde_json::from_value::<RXConfig>(json.clone()).unwrap(),
+            rx_config
+        );
+
+        let tx_config = TXConfig::new_raw(300.0, Modulation::OOK, 1.0, 0xAB, None, None)?;
+        let parsed = serde_json::from_value::<TXConfig>(serde_json::to_value(&tx_config).unwrap());
+        assert_eq!(parsed.unwrap(), tx_config);
+
+        // Out of range values in a corrupt recording are rejected rather than passed to the driver
+        for (field, value) in [
+            ("/common/frequency", 0_u32),
+            ("/common/baud_rate_exponent", 16),
+            ("/common/deviation_mantissa", 8),
+            ("/common/sync_word", 0xAAAABBBB),
+            ("/bandwidth_mantissa", 4),
+            ("/max_lna_gain", 5),
+            ("/max_dvga_gain", 3),
+            ("/magn_target", 25),
+            ("/carrier_sense", 20),
+            ("/packet_length", 0),
+        ] {
+            let mut corrupt = json.clone();
+            *corrupt.pointer_mut(field).unwrap() = value.into();
+            assert!(
+                serde_json::from_value::<RXConfig>(corrupt).is_err(),
+                "{}",
+                field
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_suggested_poll_interval() -> Result<(), CC1101Error> {
+        let mut config = RXConfig::new(
+            433.92,
+            Modulation::OOK,
+            1.0,
+            64,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        assert!((510..=514).contains(&config.suggested_poll_interval().as_millis()));
+
+        config.set_packet_length(1024)?;
+        assert!((8180..=8210).contains(&config.suggested_poll_interval().as_millis()));
+
+        config.get_common_config_mut().set_baud_rate(250.0)?;
+        config.set_packet_length(16)?;
+        assert_eq!(config.suggested_poll_interval(), Duration::from_millis(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_carson_bandwidth() {
+        assert_eq!(carson_bandwidth_khz(1.2, 5.0), 11.2);
+        assert_eq!(carson_bandwidth_khz(38.4, 20.0), 78.4);
+        assert_eq!(carson_bandwidth_khz(100.0, 47.607422), 195.214844);
+        assert_eq!(carson_bandwidth_khz(250.0, 127.0), 504.0);
+    }
+
+    #[test]
+    fn test_registers_bytes() -> Result<(), ParseError> {
+        let registers = Registers {
+            IOCFG2: 0x29,
+            SYNC1: 0xD3,
+            TEST0: 0x0B,
+            ..Default::default()
+        };
+
+        let bytes = registers.to_bytes();
+        assert_eq!(bytes[0x00], 0x29);
+        assert_eq!(bytes[0x04], 0xD3);
+        assert_eq!(bytes[0x2E], 0x0B);
+        assert_eq!(bytes.iter().filter(|byte| **byte != 0).count(), 3);
+
+        assert_eq!(Registers::from_bytes(&bytes)?.to_bytes(), bytes);
+        assert_eq!(
+            Registers::from_bytes(&bytes[..46]).err(),
+            Some(ParseError::InvalidLength(46))
+        );
+        assert_eq!(
+            Registers::from_bytes(&[0; 48]).err(),
+            Some(ParseError::InvalidLength(48))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_region() -> Result<(), CC1101Error> {
+        let config = TXConfig::new(433.92, Modulation::OOK, 1.0, 9.9, None, None)?;
+        assert_eq!(config.check_region(Region::Eu433), Ok(()));
+        assert_eq!(
+            config.check_region(Region::Us433),
+            Err(vec![ComplianceWarning::PowerAboveLimit {
+                tx_power: 9.9,
+                limit: -14.4
+            }])
+        );
+        assert_eq!(
+            config.check_region(Region::Eu868),
+            Err(vec![ComplianceWarning::OutsideBand {
+                frequency: config.get_common_config().get_frequency()
+            }])
+        );
+
+        let config = TXConfig::new(868.3, Modulation::GFSK, 38.4, 10.0, Some(20.629883), None)?;
+        assert_eq!(config.check_region(Region::Eu868), Ok(()));
+
+        // 64 bytes at ~38.4 kBaud is ~13 ms on air
+        let air_time = config.air_time(64);
+        assert!(air_time > Duration::from_millis(13) && air_time < Duration::from_millis(14));
+        assert_eq!(config.air_time(0), Duration::ZERO);
+        assert_eq!(
+            config.check_duty_cycle(Region::Eu868, 64, Duration::from_secs(10)),
+            Ok(())
+        );
+        match config.check_duty_cycle(Region::Eu868, 64, Duration::from_millis(100)) {
+            Err(warnings) => assert!(matches!(
+                warnings[..],
+                [ComplianceWarning::DutyCycleAboveLimit { limit, .. }] if limit == 0.01
+            )),
+            Ok(()) => panic!("expected duty cycle warning"),
+        }
+
+        let config =
+            TXConfig::new_raw(869.525, Modulation::GFSK, 38.4, 0xC0, Some(20.629883), None)?;
+        assert_eq!(config.check_region(Region::Eu868), Ok(()));
+        assert_eq!(
+            config.check_duty_cycle(Region::Eu868, 64, Duration::from_millis(500)),
+            Ok(())
+        );
+
+        let config = TXConfig::new(915.0, Modulation::OOK, 1.0, -29.8, None, None)?;
+        assert_eq!(config.check_region(Region::Us915), Ok(()));
+        assert_eq!(
+            config.check_duty_cycle(Region::Us915, 64, Duration::from_millis(1)),
+            Ok(())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tx_power_nearest() -> Result<(), CC1101Error> {
+        let mut config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+
+        assert_eq!(config.set_tx_power_nearest(9.9)?, 9.9);
+        assert_eq!(config.get_tx_power()?, 9.9);
+        assert_eq!(config.get_tx_power_raw(), 0xc0);
+
+        assert_eq!(config.set_tx_power_nearest(9.1)?, 9.2);
+        assert_eq!(config.set_tx_power_nearest(100.0)?, 9.9);
+        assert_eq!(config.set_tx_power_nearest(-9.95)?, -9.9);
+        assert_eq!(config.get_tx_power()?, -9.9);
+
+        let mut config = TXConfig::new_raw(400.0, Modulation::OOK, 1.0, 0xc0, None, None)?;
+        assert!(matches!(
+            config.set_tx_power_nearest(0.0),
+            Err(CC1101Error::Config(ConfigError::NoPowerTable { .. }))
+        ));
+        assert_eq!(config.get_tx_power_raw(), 0xc0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_summary() -> Result<(), CC1101Error> {
+        let config = RXConfig::new(
+            433.92,
+            Modulation::OOK,
+            1.0,
+            64,
+            None,
+            None,
+            Some(101),
+            None,
+            None,
+            None,
+            None,
+        )?;
+        assert_eq!(config.get_common_config().summary(), "433.92MHz OOK 1.0kBd");
+        assert_eq!(config.summary(), "433.92MHz OOK 1.0kBd BW 101kHz 64B");
+
+        let config = TXConfig::new(868.3, Modulation::GFSK, 38.4, 10.0, Some(20.629883), None)?;
+        assert_eq!(
+            config.get_common_config().summary(),
+            "868.30MHz GFSK 38.4kBd"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_smartrf() -> Result<(), ParseError> {
+        let registers = Registers {
+            IOCFG2: 0x29,
+            SYNC1: 0xD3,
+            SYNC0: 0x91,
+            TEST0: 0x0B,
+            ..Default::default()
+        };
+
+        let export = registers.to_smartrf();
+        let lines: Vec<&str> = export.lines().collect();
+        assert_eq!(lines.len(), 47);
+        assert_eq!(lines[0], "IOCFG2,0x29");
+        assert_eq!(lines[4], "SYNC1,0xD3");
+        assert_eq!(lines[46], "TEST0,0x0B");
+
+        assert_eq!(
+            Registers::from_smartrf(&export)?.to_bytes(),
+            registers.to_bytes()
+        );
+
+        let parsed =
+            Registers::from_smartrf("# comment\n\nsync1 0xd3\nSYNC0=91\n  PKTLEN,0xFF  \n")?;
+        assert_eq!(parsed.SYNC1, 0xD3);
+        assert_eq!(parsed.SYNC0, 0x91);
+        assert_eq!(parsed.PKTLEN, 0xFF);
+        assert_eq!(parsed.IOCFG2, 0x00);
+
+        assert_eq!(
+            Registers::from_smartrf("SYNC1,0xD3\nSYNC0").err(),
+            Some(ParseError::InvalidLine(2))
+        );
+        assert_eq!(
+            Registers::from_smartrf("FOO,0x00").err(),
+            Some(ParseError::UnknownRegister("FOO".to_string()))
+        );
+        assert_eq!(
+            Registers::from_smartrf("SYNC1,0x1FF").err(),
+            Some(ParseError::InvalidValue("0x1FF".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_radio() -> Result<(), CC1101Error> {
+        let config = RXConfig::new(
+            433.92,
+            Modulation::OOK,
+            1.0,
+            64,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        let mut other = config.clone();
+        other.set_packet_length(128)?;
+        assert!(config.matches_radio(&other));
+        assert_ne!(config, other);
+
+        other.get_common_config_mut().set_frequency(434.0)?;
+        assert!(!config.matches_radio(&other));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), CC1101Error> {
+        let config = RXConfig::default();
+        assert_eq!(config.validate(), Ok(()));
+
+        let config = RXConfig::new(
+            433.92,
+            Modulation::FSK2,
+            100.0,
+            64,
+            Some(6.347656),
+            None,
+            Some(58),
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        let warnings = config.validate().unwrap_err();
+        assert_eq!(warnings.len(), 3);
+        assert!(matches!(
+            warnings[0],
+            ConfigWarning::BandwidthTooNarrow { bandwidth: 58, .. }
+        ));
+        assert!(matches!(warnings[1], ConfigWarning::DeviationTooLow { .. }));
+        assert_eq!(warnings[2], ConfigWarning::NoRXTrigger);
+
+        let mut config = RXConfig::new(
+            433.92,
+            Modulation::OOK,
+            1.0,
+            64,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        assert_eq!(config.validate(), Err(vec![ConfigWarning::NoRXTrigger]));
+
+        config.get_common_config_mut().set_sync_word(0xd391)?;
+        assert_eq!(config.validate(), Ok(()));
+
+        config.get_common_config_mut().set_sync_word(0)?;
+        config.set_carrier_sense(Some(CarrierSense::Relative(6)))?;
+        assert_eq!(config.validate(), Ok(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimated_sensitivity() -> Result<(), CC1101Error> {
+        let mut config = RXConfig::default();
+
+        for (baud_rate, bandwidth, sensitivity) in [
+            (1.2, 58, -112.0),
+            (38.4, 101, -104.0),
+            (250.0, 541, -95.0),
+            (500.0, 812, -86.0),
+        ] {
+            config
+                .get_common_config_mut()
+                .set_modulation_and_baud_rate(Modulation::FSK2, baud_rate)?;
+            config.set_bandwidth(bandwidth)?;
+            assert!((config.estimated_sensitivity_dbm() - sensitivity).abs() < 0.1);
+        }
+
+        // Wider bandwidth than the datasheet figure reduces sensitivity
+        config
+            .get_common_config_mut()
+            .set_modulation_and_baud_rate(Modulation::OOK, 1.2)?;
+        config.set_bandwidth(812)?;
+        assert!((config.estimated_sensitivity_dbm() - -100.54).abs() < 0.1);
+
+        // Outside the table, the nearest figure is used
+        config
+            .get_common_config_mut()
+            .set_modulation_and_baud_rate(Modulation::OOK, 0.6)?;
+        config.set_bandwidth(58)?;
+        assert!((config.estimated_sensitivity_dbm() - -112.0).abs() < 0.1);
+
+        Ok(())
+    }
+
     #[test]
     fn test_tx_power() -> Result<(), CC1101Error> {
         assert!(TXConfig::config_to_tx_power(123.0, 0xFF).is_err());
@@ -974,4 +3483,45 @@ mod tests {
 
         Ok(())
     }
+
+    /// Round trips of the conversions between values and register configuration, with randomly generated inputs
+    mod round_trip {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Frequency step in MHz
+        const FREQUENCY_STEP: f32 = XTAL_FREQ / 65536.0;
+
+        proptest! {
+            #[test]
+            // The exact bounds accepted by frequency_to_config
+            fn frequency(frequency in prop_oneof![299.99976_f32..=347.99994, 386.99994_f32..=463.9998, 778.9999_f32..=928.0]) {
+                let config = CommonConfig::frequency_to_config(frequency).unwrap();
+                let quantized = CommonConfig::config_to_frequency(config);
+                prop_assert!((quantized - frequency).abs() <= FREQUENCY_STEP, "{} -> {}", frequency, quantized);
+            }
+
+            #[test]
+            fn baud_rate(baud_rate in 0.6_f32..500.0) {
+                let (mantissa, exponent) = CommonConfig::baud_rate_to_config(Modulation::FSK2, baud_rate).unwrap();
+                let quantized = CommonConfig::config_to_baud_rate(mantissa, exponent);
+
+                // One step of the mantissa at this exponent, in kBaud
+                let step = XTAL_FREQ * 1000.0 * 2_f32.powi(exponent as i32) / 2_f32.powi(28);
+                prop_assert!((quantized - baud_rate).abs() <= step, "{} -> {}", baud_rate, quantized);
+            }
+
+            #[test]
+            fn deviation(mantissa in 0_u8..8, exponent in 0_u8..8) {
+                let deviation = CommonConfig::config_to_deviation(mantissa, exponent);
+                prop_assert_eq!(CommonConfig::deviation_to_config(deviation).unwrap(), (mantissa, exponent));
+            }
+
+            #[test]
+            fn bandwidth(mantissa in 0_u8..4, exponent in 0_u8..4) {
+                let bandwidth = RXConfig::config_to_bandwidth(mantissa, exponent);
+                prop_assert_eq!(RXConfig::bandwidth_to_config(bandwidth).unwrap(), (mantissa, exponent));
+            }
+        }
+    }
 }
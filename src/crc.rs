@@ -0,0 +1,115 @@
+//! Software CRC-16 for protocols that carry a checksum the CC1101 hardware CRC cannot produce
+//!
+//! [`append_crc`] adds a CRC to a payload before transmission (see [`CC1101::set_crc`](crate::CC1101::set_crc) and [`CC1101::transmit_with_crc`](crate::CC1101::transmit_with_crc)), and [`verify_crc`] checks a received packet.
+//!
+
+/// CRC-16 variant
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CrcKind {
+    /// CRC-16/CCITT-FALSE - polynomial 0x1021, initial value 0xFFFF, not reflected. Appended most significant byte first.
+    Ccitt,
+    /// CRC-16/IBM (ARC) - polynomial 0x8005, initial value 0x0000, reflected. Appended least significant byte first.
+    Ibm,
+}
+
+/// Calculate the CRC-16 of some data
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::crc::{crc16, CrcKind};
+/// assert_eq!(crc16(CrcKind::Ccitt, b"123456789"), 0x29B1);
+/// assert_eq!(crc16(CrcKind::Ibm, b"123456789"), 0xBB3D);
+/// ```
+pub fn crc16(kind: CrcKind, data: &[u8]) -> u16 {
+    match kind {
+        CrcKind::Ccitt => data.iter().fold(0xFFFF, |crc, byte| {
+            (0..8).fold(crc ^ ((*byte as u16) << 8), |crc, _| {
+                if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                }
+            })
+        }),
+        CrcKind::Ibm => data.iter().fold(0x0000, |crc, byte| {
+            (0..8).fold(crc ^ *byte as u16, |crc, _| {
+                if crc & 0x0001 != 0 {
+                    (crc >> 1) ^ 0xA001
+                } else {
+                    crc >> 1
+                }
+            })
+        }),
+    }
+}
+
+/// Encode a CRC in the byte order used by the variant
+fn crc_bytes(kind: CrcKind, crc: u16) -> [u8; 2] {
+    match kind {
+        CrcKind::Ccitt => crc.to_be_bytes(),
+        CrcKind::Ibm => crc.to_le_bytes(),
+    }
+}
+
+/// Append the CRC-16 of a payload to it
+pub fn append_crc(kind: CrcKind, data: &[u8]) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(data.len() + 2);
+    packet.extend_from_slice(data);
+    packet.extend_from_slice(&crc_bytes(kind, crc16(kind, data)));
+    packet
+}
+
+/// Check the CRC-16 at the end of a received packet
+///
+/// Returns `false` if the packet is too short to contain a CRC.
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::crc::{append_crc, verify_crc, CrcKind};
+/// let packet = append_crc(CrcKind::Ccitt, &[0xde, 0xad, 0xbe, 0xef]);
+/// assert!(verify_crc(CrcKind::Ccitt, &packet));
+/// ```
+pub fn verify_crc(kind: CrcKind, packet: &[u8]) -> bool {
+    match packet.len().checked_sub(2) {
+        Some(length) => {
+            let (data, crc) = packet.split_at(length);
+            crc == crc_bytes(kind, crc16(kind, data))
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc16() {
+        // Check values from the CRC RevEng catalogue
+        assert_eq!(crc16(CrcKind::Ccitt, b"123456789"), 0x29B1);
+        assert_eq!(crc16(CrcKind::Ibm, b"123456789"), 0xBB3D);
+
+        assert_eq!(crc16(CrcKind::Ccitt, &[]), 0xFFFF);
+        assert_eq!(crc16(CrcKind::Ibm, &[]), 0x0000);
+    }
+
+    #[test]
+    fn test_append_verify_crc() {
+        assert_eq!(append_crc(CrcKind::Ccitt, b"123456789")[9..], [0x29, 0xB1]);
+        assert_eq!(append_crc(CrcKind::Ibm, b"123456789")[9..], [0x3D, 0xBB]);
+
+        for kind in [CrcKind::Ccitt, CrcKind::Ibm] {
+            let mut packet = append_crc(kind, &[0x01, 0x02, 0x03]);
+            assert!(verify_crc(kind, &packet));
+
+            packet[1] ^= 0x10;
+            assert!(!verify_crc(kind, &packet));
+        }
+
+        assert!(verify_crc(CrcKind::Ccitt, &[0xFF, 0xFF]));
+        assert!(!verify_crc(CrcKind::Ccitt, &[0xFF]));
+        assert!(!verify_crc(CrcKind::Ibm, &[]));
+    }
+}
@@ -0,0 +1,200 @@
+//! Helpers for decoding raw bitstreams
+//!
+//! Many simple 433 MHz devices transmit OOK data without any framing understood by the CC1101. Capturing these with a high baud rate and a
+//! carrier sense triggered [`RXConfig`](crate::config::RXConfig) yields packets of raw samples, where each bit is the state of the carrier at that point in time.
+//!
+//! The functions in this module convert these samples into data. Bitstreams are packed into bytes most significant bit first, as returned by [`CC1101::receive`](crate::CC1101::receive).
+//!
+
+use std::fmt;
+
+/// Errors encountered while decoding a bitstream
+#[derive(Debug, PartialEq)]
+pub enum DecodeError {
+    /// The symbol at the given index is not a valid Manchester symbol
+    InvalidSymbol(usize),
+    /// The number of samples per bit must be greater than zero
+    InvalidSamplesPerBit,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidSymbol(index) => {
+                write!(f, "invalid Manchester symbol at index {}", index)
+            }
+            DecodeError::InvalidSamplesPerBit => {
+                write!(f, "samples per bit must be greater than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Iterate over the bits of a packed bitstream, most significant bit first
+fn bits(data: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    data.iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1 == 1))
+}
+
+/// Pack bits into bytes, most significant bit first. A trailing partial byte is padded with zeros.
+fn pack(bits: impl Iterator<Item = bool>) -> Vec<u8> {
+    let mut bytes = vec![];
+    for (i, bit) in bits.enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0);
+        }
+        if bit {
+            if let Some(byte) = bytes.last_mut() {
+                *byte |= 0x80 >> (i % 8);
+            }
+        }
+    }
+    bytes
+}
+
+/// Reverse the order of the bits within each byte
+///
+/// Converts between MSB-first and LSB-first bit order, as used by the radio and some protocols respectively.
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::decode::reverse_bits;
+/// assert_eq!(reverse_bits(&[0x01, 0xf0]), vec![0x80, 0x0f]);
+/// ```
+pub fn reverse_bits(data: &[u8]) -> Vec<u8> {
+    data.iter().map(|byte| byte.reverse_bits()).collect()
+}
+
+/// Decode a Manchester encoded bitstream
+///
+/// Uses the IEEE 802.3 convention, where a `0` is encoded as a high-low transition (`10`) and a `1` as a low-high transition (`01`).
+///
+/// Each input byte contains four symbols, so the output is half the length of the input. As every byte holds a whole number of symbols, there is never a trailing
+/// half symbol to drop. An odd number of input bytes decodes to a trailing half byte, which is padded with zeros.
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::decode::manchester_decode;
+/// assert_eq!(manchester_decode(&[0x99, 0x66])?, vec![0x5a]);
+/// # Ok::<(), cc1101_rust::decode::DecodeError>(())
+/// ```
+pub fn manchester_decode(bits: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut decoded = Vec::with_capacity(bits.len() * 4);
+
+    let mut symbols = self::bits(bits);
+    let mut index = 0;
+    while let (Some(first), Some(second)) = (symbols.next(), symbols.next()) {
+        match (first, second) {
+            (true, false) => decoded.push(false),
+            (false, true) => decoded.push(true),
+            _ => return Err(DecodeError::InvalidSymbol(index)),
+        }
+        index += 1;
+    }
+
+    Ok(pack(decoded.into_iter()))
+}
+
+/// Convert a stream of oversampled pulses into bits
+///
+/// `samples` is a bitstream captured with the baud rate set to `samples_per_bit` times the symbol rate of the transmitter.
+/// The length of each run of high or low samples is measured and rounded to the nearest whole number of bits. Runs shorter than half a bit are treated as noise and dropped.
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::decode::pulses_to_bits;
+/// // Four samples per bit - 1, 0, 1, 1, 0, 0, 0, 0
+/// assert_eq!(pulses_to_bits(&[0xf0, 0xff, 0x00, 0x00], 4)?, vec![0xb0]);
+/// # Ok::<(), cc1101_rust::decode::DecodeError>(())
+/// ```
+pub fn pulses_to_bits(samples: &[u8], samples_per_bit: u32) -> Result<Vec<u8>, DecodeError> {
+    if samples_per_bit == 0 {
+        return Err(DecodeError::InvalidSamplesPerBit);
+    }
+
+    let mut runs: Vec<(bool, u32)> = vec![];
+    for sample in bits(samples) {
+        match runs.last_mut() {
+            Some((level, length)) if *level == sample => *length += 1,
+            _ => runs.push((sample, 1)),
+        }
+    }
+
+    let decoded = runs.into_iter().flat_map(|(level, length)| {
+        let count = (length + samples_per_bit / 2) / samples_per_bit;
+        (0..count).map(move |_| level)
+    });
+
+    Ok(pack(decoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_bits() {
+        assert_eq!(reverse_bits(&[]), Vec::<u8>::new());
+        assert_eq!(
+            reverse_bits(&[0x01, 0x80, 0xa5, 0x0f]),
+            vec![0x80, 0x01, 0xa5, 0xf0]
+        );
+        assert_eq!(reverse_bits(&reverse_bits(&[0x12, 0x34])), vec![0x12, 0x34]);
+    }
+
+    #[test]
+    fn test_manchester_decode() -> Result<(), DecodeError> {
+        assert_eq!(manchester_decode(&[])?, Vec::<u8>::new());
+        assert_eq!(manchester_decode(&[0x55, 0x55])?, vec![0xff]);
+        assert_eq!(manchester_decode(&[0xaa, 0xaa])?, vec![0x00]);
+        assert_eq!(manchester_decode(&[0x99, 0x66])?, vec![0x5a]);
+        assert_eq!(manchester_decode(&[0x99])?, vec![0x50]);
+
+        assert_eq!(
+            manchester_decode(&[0x55, 0x54]),
+            Err(DecodeError::InvalidSymbol(7))
+        );
+        assert_eq!(
+            manchester_decode(&[0xf5]),
+            Err(DecodeError::InvalidSymbol(0))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_error_display() {
+        assert_eq!(
+            DecodeError::InvalidSymbol(7).to_string(),
+            "invalid Manchester symbol at index 7"
+        );
+        assert_eq!(
+            DecodeError::InvalidSamplesPerBit.to_string(),
+            "samples per bit must be greater than zero"
+        );
+    }
+
+    #[test]
+    fn test_pulses_to_bits() -> Result<(), DecodeError> {
+        assert_eq!(pulses_to_bits(&[], 4)?, Vec::<u8>::new());
+        assert_eq!(pulses_to_bits(&[0xf0, 0xff, 0x00, 0x00], 4)?, vec![0xb0]);
+        assert_eq!(pulses_to_bits(&[0xaa], 1)?, vec![0xaa]);
+
+        // Short and long runs are rounded to the nearest bit, single sample glitches are dropped
+        assert_eq!(pulses_to_bits(&[0xe3, 0xf8], 4)?, vec![0xb0]);
+        assert_eq!(pulses_to_bits(&[0xff, 0x00], 3)?, vec![0xe0]);
+        assert_eq!(pulses_to_bits(&[0xf7, 0xf0], 4)?, vec![0xe0]);
+
+        assert_eq!(
+            pulses_to_bits(&[0xff], 0),
+            Err(DecodeError::InvalidSamplesPerBit)
+        );
+
+        Ok(())
+    }
+}
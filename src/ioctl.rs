@@ -21,6 +21,8 @@ enum Ioctl {
     GetMaxPacketSize = 10,
 }
 
+// Config structs are passed to the driver as-is. The driver runs in the same kernel as this process, so multi-byte fields such as
+// the frequency and sync word are in the host's native byte order on both sides and must not be converted.
 ioctl!(read ioctl_get_version with DEVICE_CHARACTER, Ioctl::GetVersion; u32);
 ioctl!(none ioctl_reset with DEVICE_CHARACTER, Ioctl::Reset);
 ioctl!(write ioctl_set_tx_conf with DEVICE_CHARACTER, Ioctl::SetTXConf; TXConfig);
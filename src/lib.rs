@@ -11,18 +11,41 @@
 //! * Arbitrary packet length RX/TX
 //! * Sync word or carrier sense triggered RX
 //! * 16/32 bit configurable sync word
+//!
+//! # Features
+//!
+//! * `serde` - Serialization of configuration types and recording/replay of received packets (see [`record`](crate::record))
 
 pub mod config;
+pub mod crc;
+pub mod decode;
 mod ioctl;
 mod patable;
+pub mod presets;
+#[cfg(feature = "serde")]
+pub mod record;
+pub mod rssi;
+pub mod shared;
 
-use config::{RXConfig, Registers, RegistersType, TXConfig};
-use std::fs::{File, OpenOptions};
+use config::{
+    AllRegisters, BitOrder, CarrierSense, CommonConfig, Modulation, ParseError, RXConfig,
+    Registers, RegistersType, TXConfig,
+};
+use std::borrow::Cow;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
 use std::io::{Read, Write};
+use std::os::unix::io::{FromRawFd, RawFd};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
 // Driver version
 const VERSION: u32 = 4;
 
+// Typical RSSI offset in dB from section 17.3 of the datasheet. Table 31 gives the same value for all data rates at 433 and 868 MHz.
+const RSSI_OFFSET: u8 = 74;
+
 /// Errors encountered during communication with the CC1101 driver
 #[derive(Debug)]
 pub enum DeviceError {
@@ -31,28 +54,151 @@ pub enum DeviceError {
     InvalidIOCTL,
     VersionMismatch,
     NoRXConfig,
+    NoTXConfig,
     Busy,
     Copy,
     InvalidConfig,
     OutOfMemory,
     BufferEmpty,
     PacketSize,
+    ConfigNotApplied,
     Unknown,
 }
 
+impl fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceError::NoDevice => write!(f, "device not found"),
+            DeviceError::FileHandleClone => write!(f, "unable to clone device file handle"),
+            DeviceError::InvalidIOCTL => write!(f, "invalid IOCTL"),
+            DeviceError::VersionMismatch => write!(f, "driver version mismatch"),
+            DeviceError::NoRXConfig => write!(f, "no receive configuration set"),
+            DeviceError::NoTXConfig => write!(f, "no transmit configuration set"),
+            DeviceError::Busy => write!(f, "device busy"),
+            DeviceError::Copy => write!(f, "error copying data to/from the driver"),
+            DeviceError::InvalidConfig => write!(f, "configuration rejected by the driver"),
+            DeviceError::OutOfMemory => write!(f, "driver out of memory"),
+            DeviceError::BufferEmpty => write!(f, "receive buffer empty"),
+            DeviceError::PacketSize => write!(f, "invalid packet size"),
+            DeviceError::ConfigNotApplied => {
+                write!(
+                    f,
+                    "configuration not applied by the driver before the timeout"
+                )
+            }
+            DeviceError::Unknown => write!(f, "unknown device error"),
+        }
+    }
+}
+
 /// Errors caused by device configuration
+///
+/// Variants caused by an invalid argument carry the rejected value in `got`.
 #[derive(Debug)]
 pub enum ConfigError {
-    InvalidFrequency,
-    InvalidBandwidth,
-    InvalidCarrierSense,
-    InvalidTXPower,
-    InvalidBaudRate,
-    InvalidDeviation,
-    InvalidSyncWord,
-    InvalidMaxLNAGain,
-    InvalidMaxDVGAGain,
-    InvalidMagnTarget,
+    InvalidFrequency { got: f32 },
+    InvalidBandwidth { got: u32 },
+    InvalidCarrierSense { got: CarrierSense },
+    InvalidTXPower { got: f32 },
+    InvalidTXPowerRaw { got: u8 },
+    NoPowerTable { got: f32 },
+    InvalidBaudRate { got: f32, modulation: Modulation },
+    InvalidDeviation { got: f32 },
+    UnsupportedDeviation { got: f32, modulation: Modulation },
+    InvalidSyncWord { got: u32 },
+    InvalidMaxLNAGain { got: u8 },
+    InvalidMaxDVGAGain { got: u8 },
+    InvalidMagnTarget { got: u8 },
+    InvalidChannelSpacing { got: f32 },
+    InvalidPacketLength { got: u32 },
+    InvalidPayload,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidFrequency { got } => write!(
+                f,
+                "invalid frequency {} MHz, valid values are 300-348, 387-464 and 779-928 MHz",
+                got
+            ),
+            ConfigError::InvalidBandwidth { got } => write!(
+                f,
+                "invalid bandwidth {} kHz, valid values are 58, 67, 81, 101, 116, 135, 162, 203, 232, 270, 325, 406, 464, 541, 650 and 812 kHz",
+                got
+            ),
+            ConfigError::InvalidCarrierSense { got } => write!(
+                f,
+                "invalid carrier sense {}, valid values are Relative(6/10/14) and Absolute(-7 to 7)",
+                got
+            ),
+            ConfigError::InvalidTXPower { got } => write!(
+                f,
+                "invalid TX power {} dBm, not found in the power table for the configured frequency",
+                got
+            ),
+            ConfigError::InvalidTXPowerRaw { got } => write!(
+                f,
+                "invalid TX power 0x{:02x}, not found in the power table for the configured frequency",
+                got
+            ),
+            ConfigError::NoPowerTable { got } => write!(
+                f,
+                "no TX power table for {} MHz, frequency must be within 1 MHz of 315/433/868/915 MHz to set TX power in dBm (use a raw PATABLE value instead)",
+                got
+            ),
+            ConfigError::InvalidBaudRate { got, modulation } => {
+                let range = CommonConfig::baud_rate_range(*modulation);
+                write!(
+                    f,
+                    "invalid baud rate {} kBaud for {:?}, valid values are {:.1} - {:.1} kBaud",
+                    got,
+                    modulation,
+                    range.start(),
+                    range.end()
+                )
+            }
+            ConfigError::InvalidDeviation { got } => {
+                write!(f, "invalid deviation {} kHz, must be one of the discrete values between 1.586914 and 380.859375 kHz", got)
+            }
+            ConfigError::UnsupportedDeviation { got, modulation } => write!(
+                f,
+                "deviation {} kHz is not used with {:?}, it must not be specified",
+                got, modulation
+            ),
+            ConfigError::InvalidSyncWord { got } => write!(
+                f,
+                "invalid sync word 0x{:08x}, above 0xFFFF the high and low 16 bits must match",
+                got
+            ),
+            ConfigError::InvalidMaxLNAGain { got } => write!(
+                f,
+                "invalid max LNA gain {} dB, valid values are 0, 3, 6, 7, 9, 12, 15 and 17 dB",
+                got
+            ),
+            ConfigError::InvalidMaxDVGAGain { got } => write!(
+                f,
+                "invalid max DVGA gain {} dB, valid values are 0, 6, 12 and 18 dB",
+                got
+            ),
+            ConfigError::InvalidMagnTarget { got } => write!(
+                f,
+                "invalid magn target {} dB, valid values are 24, 27, 30, 33, 36, 38, 40 and 42 dB",
+                got
+            ),
+            ConfigError::InvalidChannelSpacing { got } => write!(
+                f,
+                "invalid channel spacing {} kHz, valid values are 25.390625 - 405.456543 kHz",
+                got
+            ),
+            ConfigError::InvalidPacketLength { got } => write!(
+                f,
+                "invalid packet length {} bytes, must be at least 1 byte",
+                got
+            ),
+            ConfigError::InvalidPayload => write!(f, "invalid payload"),
+        }
+    }
 }
 
 /// Generic type for errors thrown by the module
@@ -60,8 +206,89 @@ pub enum ConfigError {
 pub enum CC1101Error {
     Device(DeviceError),
     Config(ConfigError),
+    Io(std::io::Error),
+    Parse(ParseError),
+}
+
+impl From<std::io::Error> for CC1101Error {
+    fn from(e: std::io::Error) -> Self {
+        CC1101Error::Io(e)
+    }
+}
+
+impl From<ParseError> for CC1101Error {
+    fn from(e: ParseError) -> Self {
+        CC1101Error::Parse(e)
+    }
+}
+
+impl fmt::Display for CC1101Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CC1101Error::Device(e) => write!(f, "device error: {}", e),
+            CC1101Error::Config(e) => write!(f, "config error: {}", e),
+            CC1101Error::Io(e) => write!(f, "I/O error: {}", e),
+            CC1101Error::Parse(e) => write!(f, "parse error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CC1101Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CC1101Error::Io(e) => Some(e),
+            CC1101Error::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The state of a device captured by [`CC1101::snapshot`], which can be put back with [`CC1101::restore`]
+#[derive(Debug)]
+pub struct DeviceSnapshot {
+    /// Whether the device was receiving
+    ///
+    /// The driver does not report whether it is in RX, so [`CC1101::snapshot`] sets this if RX had been started by the [`CC1101`] taking the snapshot.
+    /// Set it to `true` if RX is known to have been started by another process.
+    pub rx_active: bool,
+    /// Receive configuration set in the driver
+    pub rx_config: RXConfig,
+    /// Transmit configuration set in the driver
+    pub tx_config: TXConfig,
+    /// Device registers and the driver's transmit and receive configuration registers
+    pub registers: AllRegisters,
+}
+
+/// A record of a transmitted packet, passed to the hook set with [`CC1101::set_tx_hook`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxRecord {
+    /// Time at which the packet finished being written to the driver
+    pub timestamp: SystemTime,
+    /// Frequency in MHz
+    pub frequency: f32,
+    /// TX power in dBm, or `None` if the configuration uses a raw PATABLE value not in the power table
+    pub tx_power: Option<f32>,
+    /// Length of the payload in bytes
+    pub payload_length: usize,
+    /// Estimated time on air, as per [`TXConfig::air_time`]
+    pub air_time: Duration,
+}
+
+impl TxRecord {
+    fn new(tx_config: &TXConfig, payload_length: usize) -> TxRecord {
+        TxRecord {
+            timestamp: SystemTime::now(),
+            frequency: tx_config.get_common_config().get_frequency(),
+            tx_power: tx_config.get_tx_power().ok(),
+            payload_length,
+            air_time: tx_config.air_time(payload_length),
+        }
+    }
 }
 
+/// Hook called with a [`TxRecord`] for every transmitted packet
+pub type TxHook = Box<dyn Fn(&TxRecord) + Send + Sync>;
+
 /// CC1101 radio device
 ///
 /// This struct provides a handle to a CC1101 device, presented by the [Linux Driver](https://github.com/28757B2/cc1101-driver) as a character device (e.g `/dev/cc1101.0.0`).
@@ -107,10 +334,19 @@ pub enum CC1101Error {
 ///
 /// Note - sharing a device between two receiving processes will cause packet loss, as the driver's internal packet buffer is reset each time a new receive configuration is set.
 ///
+/// To share a device between threads within a process, use [`SharedCC1101`](crate::shared::SharedCC1101).
+///
 pub struct CC1101 {
     device: String,
     handle: Option<File>,
     rx_config: Option<RXConfig>,
+    tx_config: Option<TXConfig>,
+    rx_active: AtomicBool,
+    tx_active: AtomicUsize,
+    bit_order: BitOrder,
+    crc: Option<crc::CrcKind>,
+    rssi_offset: Option<u8>,
+    tx_hook: Option<TxHook>,
 }
 
 impl CC1101 {
@@ -122,7 +358,7 @@ impl CC1101 {
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// # use cc1101_rust::{CC1101, config::{RXConfig, Modulation}};
     /// let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
     /// let cc1101 = CC1101::new("/dev/cc1101.0.0", Some(rx_config), false)?;
@@ -134,32 +370,170 @@ impl CC1101 {
         blocking: bool,
     ) -> Result<CC1101, CC1101Error> {
         let handle = Self::open(device)?;
+        Self::from_handle(device.to_string(), handle, rx_config, blocking)
+    }
+
+    /// Create a new handle to a CC1101 device from an already open file descriptor
+    ///
+    /// This is intended for deployments where the device is opened by another process (e.g a supervisor or systemd socket activation) and the file descriptor passed in.
+    /// The driver version is checked before the descriptor is used.
+    ///
+    /// The returned [`CC1101`] takes ownership of `fd`:
+    ///
+    /// * In blocking mode, `fd` is held open and is closed when the [`CC1101`] is dropped.
+    /// * In non-blocking mode, the device path is resolved from `/proc/self/fd` and `fd` is closed once any `rx_config` has been applied.
+    ///   The device is then re-opened by path for each operation, as with [`CC1101::new`].
+    ///
+    /// `fd` is also closed if an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open file descriptor that is not owned or closed elsewhere, as with [`FromRawFd::from_raw_fd`].
+    pub unsafe fn from_raw_fd(
+        fd: RawFd,
+        rx_config: Option<RXConfig>,
+        blocking: bool,
+    ) -> Result<CC1101, CC1101Error> {
+        let handle = File::from_raw_fd(fd);
+        Self::check_version(&handle)?;
+
+        let device = match fs::read_link(format!("/proc/self/fd/{}", fd)) {
+            Ok(path) => path.to_string_lossy().into_owned(),
+            Err(_) if blocking => String::new(),
+            Err(_) => return Err(CC1101Error::Device(DeviceError::NoDevice)),
+        };
 
+        Self::from_handle(device, handle, rx_config, blocking)
+    }
+
+    /// Apply the initial receive configuration and either hold or release the file handle
+    fn from_handle(
+        device: String,
+        handle: File,
+        rx_config: Option<RXConfig>,
+        blocking: bool,
+    ) -> Result<CC1101, CC1101Error> {
         if let Some(rx_config) = &rx_config {
-            Self::set_rx_config_on_device(&handle, &None, rx_config, blocking)?;
+            Self::check_packet_length(&handle, rx_config)?;
+            Self::set_rx_config_on_device(&handle, &None, rx_config, blocking, false)?;
         }
 
-        match blocking {
-            true => Ok(CC1101 {
-                device: device.to_string(),
-                handle: Some(handle),
-                rx_config,
-            }),
-            false => Ok(CC1101 {
-                device: device.to_string(),
-                handle: None,
-                rx_config,
-            }),
-        }
+        let handle = match blocking {
+            true => Some(handle),
+            false => None,
+        };
+
+        Ok(CC1101 {
+            device,
+            handle,
+            rx_active: AtomicBool::new(rx_config.is_some()),
+            rx_config,
+            tx_config: None,
+            tx_active: AtomicUsize::new(0),
+            bit_order: BitOrder::default(),
+            crc: None,
+            rssi_offset: None,
+            tx_hook: None,
+        })
+    }
+
+    /// Create a new handle to a CC1101 device used for both receive and transmit
+    ///
+    /// This behaves as [`CC1101::new`] with `rx_config`, and also stores `tx_config` for use by [`CC1101::transmit_default`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{RXConfig, TXConfig, Modulation}};
+    /// let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::transceiver("/dev/cc1101.0.0", rx_config, tx_config, false)?;
+    ///
+    /// cc1101.transmit_default(&[0x0f, 0x0f, 0x0f, 0x0f])?;
+    /// let packets = cc1101.receive()?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn transceiver(
+        device: &str,
+        rx_config: RXConfig,
+        tx_config: TXConfig,
+        blocking: bool,
+    ) -> Result<CC1101, CC1101Error> {
+        let mut cc1101 = Self::new(device, Some(rx_config), blocking)?;
+        cc1101.tx_config = Some(tx_config);
+        Ok(cc1101)
     }
 
     /// Get the current RSSI value from the radio
+    ///
+    /// The RSSI is not meaningful while the radio is transmitting, so this returns [`DeviceError::Busy`] if a transmit is in progress on this [`CC1101`]
+    /// (e.g. from another thread). Transmits by other processes sharing the device cannot be detected.
     pub fn get_rssi(&self) -> Result<u8, CC1101Error> {
+        if self.tx_active.load(Ordering::Acquire) > 0 {
+            return Err(CC1101Error::Device(DeviceError::Busy));
+        }
+
         let handle = self.get_handle()?;
         ioctl::get_rssi(&handle)
     }
 
+    /// Get the current RSSI value from the radio in dBm
+    ///
+    /// Converted using the formula from section 17.3 of the datasheet, with the RSSI offset from [`CC1101::get_rssi_offset`].
+    pub fn get_rssi_dbm(&self) -> Result<f32, CC1101Error> {
+        Ok(rssi::rssi_raw_to_dbm(
+            self.get_rssi()?,
+            self.get_rssi_offset(),
+        ))
+    }
+
+    /// Measure the average RSSI in dBm over a period of time
+    ///
+    /// Takes `samples` readings using [`CC1101::get_rssi_dbm`], `interval` apart, and averages them in linear units (see [`rssi::average_dbm`]).
+    /// At least one sample is always taken.
+    pub fn rssi_average(&self, samples: usize, interval: Duration) -> Result<f32, CC1101Error> {
+        let samples = samples.max(1);
+
+        let mut levels = Vec::with_capacity(samples);
+        for i in 0..samples {
+            if i > 0 {
+                thread::sleep(interval);
+            }
+            levels.push(self.get_rssi_dbm()?);
+        }
+
+        Ok(rssi::average_dbm(&levels).unwrap_or(f32::NEG_INFINITY))
+    }
+
+    /// Set the RSSI offset in dB used by [`CC1101::get_rssi_dbm`]
+    ///
+    /// The offset varies between boards, so this allows a value calibrated against a known signal level to be used instead of the datasheet typical.
+    pub fn set_rssi_offset(&mut self, rssi_offset: u8) {
+        self.rssi_offset = Some(rssi_offset);
+    }
+
+    /// Get the RSSI offset in dB used by [`CC1101::get_rssi_dbm`]
+    ///
+    /// Defaults to the typical value of 74 dB from section 17.3 of the datasheet, which applies to all frequency bands and data rates.
+    pub fn get_rssi_offset(&self) -> u8 {
+        self.rssi_offset.unwrap_or(RSSI_OFFSET)
+    }
+
+    /// Estimate the link margin in dB from the current RSSI and the configured receive config
+    ///
+    /// This is the difference between [`CC1101::get_rssi_dbm`] and [`RXConfig::estimated_sensitivity_dbm`]. As the sensitivity is a rough estimate, so is the link margin.
+    pub fn link_margin(&self) -> Result<f32, CC1101Error> {
+        match &self.rx_config {
+            Some(rx_config) => Ok(self.get_rssi_dbm()? - rx_config.estimated_sensitivity_dbm()),
+            None => Err(CC1101Error::Device(DeviceError::NoRXConfig)),
+        }
+    }
+
     /// Get the maximum packet size configured in the driver
+    ///
+    /// This limits the packet length of receive configurations and the size of each transmitted packet.
+    ///
+    /// The driver does not provide an IOCTL to change this limit at runtime. It is set when the driver module is loaded,
+    /// using the `max_packet_size` module parameter (e.g `modprobe cc1101 max_packet_size=2048`).
     pub fn get_max_packet_size(&self) -> Result<u32, CC1101Error> {
         let handle = self.get_handle()?;
         ioctl::get_max_packet_size(&handle)
@@ -193,173 +567,943 @@ impl CC1101 {
     /// # Ok::<(), cc1101_rust::CC1101Error>(())
     /// ```
     pub fn receive(&self) -> Result<Vec<Vec<u8>>, CC1101Error> {
-        if let Some(rx_config) = &self.rx_config {
-            let mut handle = self.get_handle()?;
-            Self::set_rx_config_on_device(
-                &handle,
-                &self.rx_config,
-                rx_config,
-                self.handle.is_some(),
-            )?;
+        self.receive_up_to(usize::MAX)
+    }
 
-            let mut packets = vec![];
-            loop {
-                let mut packet = vec![0; rx_config.get_packet_length() as usize];
-                match handle.read(&mut packet) {
-                    Ok(_) => {
-                        packets.push(packet);
-                    }
-                    Err(e) => match e.raw_os_error() {
-                        Some(libc::ENOMSG) => break,
-                        Some(libc::EMSGSIZE) => {
-                            return Err(CC1101Error::Device(DeviceError::PacketSize))
-                        }
-                        Some(libc::EBUSY) => return Err(CC1101Error::Device(DeviceError::Busy)),
-                        Some(libc::EINVAL) => {
-                            return Err(CC1101Error::Device(DeviceError::InvalidConfig))
-                        }
-                        Some(libc::EFAULT) => return Err(CC1101Error::Device(DeviceError::Copy)),
-                        _ => {
-                            return Err(CC1101Error::Device(DeviceError::Unknown));
-                        }
-                    },
-                }
-            }
+    /// Receive at most `max` packets from the radio
+    ///
+    /// This behaves as [`CC1101::receive`], but stops reading once `max` packets have been read. Any further packets remain buffered in the driver until the next receive call.
+    ///
+    /// This limits the memory allocated by a single call if a large number of packets have been received.
+    pub fn receive_up_to(&self, max: usize) -> Result<Vec<Vec<u8>>, CC1101Error> {
+        let (mut handle, packet_length) = self.start_receive()?;
 
-            Ok(packets)
-        } else {
-            Err(CC1101Error::Device(DeviceError::NoRXConfig))
+        let mut packets = vec![];
+        while packets.len() < max {
+            match Self::read_packet(&mut handle, packet_length)? {
+                Some(packet) => packets.push(self.bit_order.decode(packet)),
+                None => break,
+            }
         }
+
+        Ok(packets)
     }
 
-    /// Transmit a packet via the radio using the provided configuration
+    /// Receive packets from the radio as fixed size arrays
+    ///
+    /// This behaves as [`CC1101::receive`], for protocols with a known frame size. Returns [`DeviceError::PacketSize`] if the packet length of the
+    /// receive configuration is not `N`, and [`DeviceError::NoRXConfig`] if there is no receive configuration.
     ///
     /// # Example
-    /// ```no_run
-    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
-    /// const PACKET: [u8; 11] = [0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f];       
     ///
-    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
-    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{RXConfig, Modulation}};
+    /// let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 8, None, None, None, None, None, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", Some(rx_config), false)?;
     ///
-    /// cc1101.transmit(&tx_config, &PACKET)?;
+    /// for packet in cc1101.receive_fixed::<8>()? {
+    ///     println!("Received - {:x?}", packet);
+    /// }
     /// # Ok::<(), cc1101_rust::CC1101Error>(())
     /// ```
-    ///
-    pub fn transmit(&self, tx_config: &TXConfig, data: &[u8]) -> Result<(), CC1101Error> {
-        let mut handle = self.get_handle()?;
-
-        Self::set_tx_config_on_device(&handle, tx_config)?;
-
-        match handle.write(data) {
-            Ok(_) => Ok(()),
-            Err(e) => match e.raw_os_error() {
-                Some(libc::EINVAL) => Err(CC1101Error::Device(DeviceError::PacketSize)),
-                Some(libc::ENOMEM) => Err(CC1101Error::Device(DeviceError::OutOfMemory)),
-                Some(libc::EFAULT) => Err(CC1101Error::Device(DeviceError::Copy)),
-                _ => Err(CC1101Error::Device(DeviceError::Unknown)),
-            },
-        }
-    }
-
-    /// Open a file handle to the device
-    fn open(device: &str) -> Result<File, CC1101Error> {
-        let handle = match OpenOptions::new().read(true).write(true).open(device) {
-            Ok(file) => file,
-            Err(e) => match e.raw_os_error() {
-                Some(libc::EBUSY) => return Err(CC1101Error::Device(DeviceError::Busy)),
-                _ => return Err(CC1101Error::Device(DeviceError::Unknown)),
-            },
-        };
-
-        let version = ioctl::get_version(&handle)?;
-
-        if version != VERSION {
-            return Err(CC1101Error::Device(DeviceError::VersionMismatch));
+    pub fn receive_fixed<const N: usize>(&self) -> Result<Vec<[u8; N]>, CC1101Error> {
+        match &self.rx_config {
+            Some(rx_config) if rx_config.get_packet_length() as usize != N => {
+                return Err(CC1101Error::Device(DeviceError::PacketSize))
+            }
+            Some(_) => (),
+            None => return Err(CC1101Error::Device(DeviceError::NoRXConfig)),
         }
 
-        Ok(handle)
+        self.receive()?
+            .into_iter()
+            .map(|packet| {
+                packet
+                    .try_into()
+                    .map_err(|_| CC1101Error::Device(DeviceError::PacketSize))
+            })
+            .collect()
     }
 
-    /// Get a handle to the device.
+    /// Listen with each of several receive configurations in turn, e.g to receive packets with different sync words
     ///
-    /// Either re-use the existing handle if in blocking mode, or create a new one.
-    fn get_handle(&self) -> Result<File, CC1101Error> {
-        if let Some(handle) = &self.handle {
-            match handle.try_clone() {
-                Ok(h) => Ok(h),
-                Err(_) => Err(CC1101Error::Device(DeviceError::FileHandleClone)),
+    /// Each configuration is set for `dwell`, after which any received packets are read and tagged with the index of the configuration in `configs`.
+    /// This makes one pass over `configs` - call it in a loop to listen continuously.
+    ///
+    /// Packets transmitted while a different configuration is active, or while switching configuration, will be missed.
+    ///
+    /// Once complete, the original receive configuration is restored, or RX is stopped if there was none. This also happens if an error occurs.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use cc1101_rust::{CC1101, config::{RXConfig, Modulation}};
+    /// let configs = [
+    ///     RXConfig::new(433.92, Modulation::FSK2, 38.4, 16, Some(20.629883), Some(0xd391), None, None, None, None, None)?,
+    ///     RXConfig::new(433.92, Modulation::FSK2, 38.4, 16, Some(20.629883), Some(0x2dd4), None, None, None, None, None)?,
+    /// ];
+    /// let mut cc1101 = CC1101::new("/dev/cc1101.0.0", None, true)?;
+    ///
+    /// loop {
+    ///     for (index, packet) in cc1101.multi_sync_listen(&configs, Duration::from_millis(500))? {
+    ///         println!("Received with config {} - {:x?}", index, packet);
+    ///     }
+    /// }
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn multi_sync_listen(
+        &mut self,
+        configs: &[RXConfig],
+        dwell: Duration,
+    ) -> Result<Vec<(usize, Vec<u8>)>, CC1101Error> {
+        self.with_temporary_rx_config(|cc1101| {
+            let mut packets = vec![];
+            for (index, config) in configs.iter().enumerate() {
+                cc1101.set_rx_config(config)?;
+                thread::sleep(dwell);
+                packets.extend(cc1101.receive()?.into_iter().map(|packet| (index, packet)));
             }
-        } else {
-            Ok(Self::open(&self.device)?)
-        }
+            Ok(packets)
+        })
     }
 
-    /// Issue a reset command to the device.
+    /// Receive for a single window with a different modulation and baud rate, e.g to monitor two protocols on the same frequency
     ///
-    /// This will clear the received packet buffer and stop receiving. Packet reception can be resumed by calling [`CC1101::receive`].
-    pub fn reset(&mut self) -> Result<(), CC1101Error> {
-        ioctl::reset(&self.get_handle()?)
+    /// The current receive configuration (or [`RXConfig::default`] if there is none) is set with `modulation` and `baud_rate` for `window`, after which any received packets are read.
+    /// The modulation and baud rate must be valid together, as per [`CommonConfig::set_modulation_and_baud_rate`]. If not, an error is returned without changing anything.
+    ///
+    /// Once complete, the original receive configuration is restored, or RX is stopped if there was none. This also happens if an error occurs.
+    /// Packets buffered under the original configuration are discarded, as setting a receive configuration resets the driver's packet buffer.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use cc1101_rust::{CC1101, config::{RXConfig, Modulation}};
+    /// let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+    /// let mut cc1101 = CC1101::new("/dev/cc1101.0.0", Some(rx_config), true)?;
+    ///
+    /// for packet in cc1101.receive_as(Modulation::FSK2, 4.8, Duration::from_millis(500))? {
+    ///     println!("Received FSK - {:x?}", packet);
+    /// }
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn receive_as(
+        &mut self,
+        modulation: Modulation,
+        baud_rate: f32,
+        window: Duration,
+    ) -> Result<Vec<Vec<u8>>, CC1101Error> {
+        let mut rx_config = self.rx_config.clone().unwrap_or_default();
+        rx_config
+            .get_common_config_mut()
+            .set_modulation_and_baud_rate(modulation, baud_rate)?;
+
+        self.with_temporary_rx_config(|cc1101| {
+            cc1101.set_rx_config(&rx_config)?;
+            thread::sleep(window);
+            cc1101.receive()
+        })
     }
 
-    fn set_tx_config_on_device(handle: &File, tx_config: &TXConfig) -> Result<(), CC1101Error> {
-        ioctl::set_tx_conf(handle, tx_config)
+    /// Measure the RSSI on each of a list of frequencies
+    ///
+    /// The current receive configuration (or [`RXConfig::default`] if there is none) is set with each frequency in turn for `dwell`, after which the RSSI is read.
+    /// Returns each frequency paired with its RSSI in dBm.
+    ///
+    /// Every frequency is checked before the radio is reconfigured, so an invalid frequency returns an error without changing anything.
+    /// Once complete, the original receive configuration is restored, or RX is stopped if there was none. This also happens if an error occurs.
+    /// Any packets received during the scan are discarded, as setting a receive configuration resets the driver's packet buffer.
+    pub fn scan(
+        &mut self,
+        frequencies: &[f32],
+        dwell: Duration,
+    ) -> Result<Vec<(f32, f32)>, CC1101Error> {
+        let base_config = self.rx_config.clone().unwrap_or_default();
+
+        let configs = frequencies
+            .iter()
+            .map(|frequency| {
+                let mut rx_config = base_config.clone();
+                rx_config
+                    .get_common_config_mut()
+                    .set_frequency(*frequency)?;
+                Ok((*frequency, rx_config))
+            })
+            .collect::<Result<Vec<_>, CC1101Error>>()?;
+
+        self.with_temporary_rx_config(|cc1101| {
+            let mut levels = vec![];
+            for (frequency, rx_config) in &configs {
+                cc1101.set_rx_config(rx_config)?;
+                thread::sleep(dwell);
+                levels.push((*frequency, cc1101.get_rssi_dbm()?));
+            }
+            Ok(levels)
+        })
     }
 
-    /// Set the receive configuration.
+    /// Find the frequency with the strongest signal above `threshold_dbm`
     ///
-    /// This will configure the driver for RX with the provided configuration and begin packet reception. Received packets can be read using [`CC1101::receive`].
+    /// This uses [`CC1101::scan`] to measure the RSSI on each frequency, returning [`None`] if none exceed the threshold.
     ///
-    pub fn set_rx_config(&mut self, rx_config: &RXConfig) -> Result<(), CC1101Error> {
-        Self::set_rx_config_on_device(
-            &self.get_handle()?,
-            &self.rx_config,
-            rx_config,
-            self.handle.is_some(),
-        )?;
-        self.rx_config = Some(rx_config.clone());
-        Ok(())
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use cc1101_rust::CC1101;
+    /// let mut cc1101 = CC1101::new("/dev/cc1101.0.0", None, true)?;
+    ///
+    /// let frequencies = [433.82, 433.87, 433.92, 433.97, 434.02];
+    /// if let Some(frequency) = cc1101.find_active_channel(&frequencies, Duration::from_millis(50), -80.0)? {
+    ///     println!("Signal found on {} MHz", frequency);
+    /// }
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn find_active_channel(
+        &mut self,
+        frequencies: &[f32],
+        dwell: Duration,
+        threshold_dbm: f32,
+    ) -> Result<Option<f32>, CC1101Error> {
+        Ok(self
+            .scan(frequencies, dwell)?
+            .into_iter()
+            .filter(|(_, rssi)| *rssi > threshold_dbm)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(frequency, _)| frequency))
     }
 
-    fn set_rx_config_on_device(
-        handle: &File,
-        old_config: &Option<RXConfig>,
-        new_config: &RXConfig,
-        blocking: bool,
-    ) -> Result<(), CC1101Error> {
-        // Does the new config match the saved config
-        let configs_match = match old_config {
-            Some(old_config) => old_config == new_config,
-            None => false,
-        };
+    /// Run `f`, which may change the receive configuration, then restore the original configuration
+    ///
+    /// The configuration is restored even if `f` fails. The first error from `f` or the restore is returned.
+    fn with_temporary_rx_config<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, CC1101Error>,
+    ) -> Result<T, CC1101Error> {
+        let original_config = self.rx_config.clone();
+        let result = f(self);
+        let restored = self.restore_rx_config(original_config);
 
-        if configs_match {
-            // In non-blocking mode, the RX config on the device may become of out sync with the saved config
-            if !blocking {
-                // Get the current config on the device
-                let current_device_config = ioctl::get_rx_conf(handle)?;
+        let value = result?;
+        restored?;
+        Ok(value)
+    }
 
-                // Update the device if the config on the device and the saved config differ
-                if current_device_config != *new_config {
-                    ioctl::set_rx_conf(handle, new_config)?;
-                }
+    /// Restore a receive configuration saved before temporarily changing it, stopping RX if there was none
+    fn restore_rx_config(&mut self, rx_config: Option<RXConfig>) -> Result<(), CC1101Error> {
+        match rx_config {
+            Some(config) => self.set_rx_config(&config)?,
+            None => {
+                self.reset()?;
+                self.rx_config = None;
             }
-        } else {
-            ioctl::set_rx_conf(handle, new_config)?;
         }
         Ok(())
     }
 
-    /// Get the configured receive config
-    pub fn get_rx_config(&self) -> &Option<RXConfig> {
-        &self.rx_config
+    /// Receive packets from the radio, tagged with the time they were read
+    ///
+    /// This behaves as [`CC1101::receive`], but pairs each packet with the [`Instant`] at which it was read from the driver.
+    ///
+    /// Note - this is the time the packet was read into userspace, not the time it was received by the radio.
+    /// Packets are buffered by the driver until read, so all packets returned by one call will have similar timestamps, which may be long after they were received.
+    /// Polling more frequently reduces this error.
+    pub fn receive_timestamped(&self) -> Result<Vec<(Instant, Vec<u8>)>, CC1101Error> {
+        let (mut handle, packet_length) = self.start_receive()?;
+
+        let mut packets = vec![];
+        while let Some(packet) = Self::read_packet(&mut handle, packet_length)? {
+            packets.push((Instant::now(), self.bit_order.decode(packet)));
+        }
+
+        Ok(packets)
     }
 
-    /// Get the transmit configuration currently set in the driver
-    pub fn get_device_tx_config(&mut self) -> Result<TXConfig, CC1101Error> {
+    /// Ensure the driver is in RX with the saved receive config, returning a handle to read packets from and the packet length
+    fn start_receive(&self) -> Result<(File, usize), CC1101Error> {
+        if let Some(rx_config) = &self.rx_config {
+            let handle = self.get_handle()?;
+            Self::set_rx_config_on_device(
+                &handle,
+                &self.rx_config,
+                rx_config,
+                self.handle.is_some(),
+                self.rx_active.load(Ordering::Relaxed),
+            )?;
+            self.rx_active.store(true, Ordering::Relaxed);
+
+            Ok((handle, rx_config.get_packet_length() as usize))
+        } else {
+            Err(CC1101Error::Device(DeviceError::NoRXConfig))
+        }
+    }
+
+    /// Read a single packet from the driver's receive buffer, returning [`None`] if the buffer is empty
+    ///
+    /// The driver returns one whole packet per `read()`, so a short read is reported as [`DeviceError::PacketSize`].
+    fn read_packet<R: Read>(
+        handle: &mut R,
+        packet_length: usize,
+    ) -> Result<Option<Vec<u8>>, CC1101Error> {
+        // A zero length read always succeeds, so would never report an empty buffer
+        if packet_length == 0 {
+            return Err(CC1101Error::Device(DeviceError::PacketSize));
+        }
+
+        let mut packet = vec![0; packet_length];
+        match handle.read(&mut packet) {
+            Ok(length) if length == packet_length => Ok(Some(packet)),
+            Ok(_) => Err(CC1101Error::Device(DeviceError::PacketSize)),
+            Err(e) => match e.raw_os_error() {
+                // No packet buffered, or none available yet on a non-blocking handle
+                Some(libc::ENOMSG) | Some(libc::EAGAIN) => Ok(None),
+                Some(libc::EMSGSIZE) => Err(CC1101Error::Device(DeviceError::PacketSize)),
+                Some(libc::EBUSY) => Err(CC1101Error::Device(DeviceError::Busy)),
+                Some(libc::EINVAL) => Err(CC1101Error::Device(DeviceError::InvalidConfig)),
+                Some(libc::EFAULT) => Err(CC1101Error::Device(DeviceError::Copy)),
+                _ => Err(CC1101Error::Device(DeviceError::Unknown)),
+            },
+        }
+    }
+
+    /// Transmit a packet via the radio using the provided configuration
+    ///
+    /// If the driver is in RX, reception is paused while transmitting and resumed with the same receive configuration once TX completes.
+    /// This call does not set a new receive configuration, so the driver's buffer of received packets is not reset. Subsequent calls to [`CC1101::receive`] only
+    /// re-send the receive configuration if the one on the device differs from the saved one.
+    ///
+    /// Packets that arrive while the radio is transmitting cannot be received.
+    ///
+    /// The minimum packet length is 1 byte - an empty `data` returns [`ConfigError::InvalidPayload`] without configuring the radio.
+    ///
+    /// If a software CRC has been set with [`CC1101::set_crc`], it is appended to `data` before transmission.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
+    /// const PACKET: [u8; 11] = [0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f, 0x0f];       
+    ///
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// cc1101.transmit(&tx_config, &PACKET)?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    ///
+    pub fn transmit(&self, tx_config: &TXConfig, data: &[u8]) -> Result<(), CC1101Error> {
+        self.transmit_packet(tx_config, data, self.crc)
+    }
+
+    /// Transmit a single packet, appending a software CRC if `crc` is provided
+    fn transmit_packet(
+        &self,
+        tx_config: &TXConfig,
+        data: &[u8],
+        crc: Option<crc::CrcKind>,
+    ) -> Result<(), CC1101Error> {
+        if data.is_empty() {
+            return Err(CC1101Error::Config(ConfigError::InvalidPayload));
+        }
+
+        let _tx_guard = TxGuard::new(&self.tx_active);
+        let mut handle = self.get_handle()?;
+
+        Self::set_tx_config_on_device(&handle, tx_config)?;
+
+        let packet = Self::encode_packet(data, crc, self.bit_order);
+        Self::write_packet(&mut handle, &packet)?;
+        self.record_tx(tx_config, packet.len());
+
+        Ok(())
+    }
+
+    /// Transmit a packet via the radio using a copy of the provided configuration with a different TX power
+    ///
+    /// `tx_power` is in dBm, as per [`TXConfig::set_tx_power`]. `tx_config` is not modified, so the next transmission with it uses its own power setting.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, -10.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// cc1101.transmit_with_power(&tx_config, &[0x0f, 0x0f], 9.9)?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn transmit_with_power(
+        &self,
+        tx_config: &TXConfig,
+        data: &[u8],
+        tx_power: f32,
+    ) -> Result<(), CC1101Error> {
+        let mut tx_config = tx_config.clone();
+        tx_config.set_tx_power(tx_power)?;
+        self.transmit(&tx_config, data)
+    }
+
+    /// Transmit a packet via the radio using the stored transmit configuration
+    ///
+    /// This behaves as [`CC1101::transmit`], using the configuration provided to [`CC1101::transceiver`] or [`CC1101::set_default_tx_config`].
+    /// Returns [`DeviceError::NoTXConfig`] if no transmit configuration is stored.
+    pub fn transmit_default(&self, data: &[u8]) -> Result<(), CC1101Error> {
+        match &self.tx_config {
+            Some(tx_config) => self.transmit(tx_config, data),
+            None => Err(CC1101Error::Device(DeviceError::NoTXConfig)),
+        }
+    }
+
+    /// Transmit the contents of a [`Read`] source via the radio using the provided configuration
+    ///
+    /// The TX config is set once, then the source is read in chunks of `chunk_len` bytes, each of which is transmitted as a separate packet.
+    /// Chunks are limited to the driver's maximum packet size (see [`CC1101::get_max_packet_size`]). The final chunk may be shorter.
+    ///
+    /// If a software CRC has been set with [`CC1101::set_crc`], it is appended to each chunk, and chunks are shortened by 2 bytes to leave room for it.
+    ///
+    /// Returns the total number of bytes read from `source` and transmitted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
+    /// # use std::fs::File;
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// let mut file = File::open("payload.bin")?;
+    /// cc1101.transmit_from(&tx_config, &mut file, 64)?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn transmit_from<R: Read>(
+        &self,
+        tx_config: &TXConfig,
+        source: &mut R,
+        chunk_len: usize,
+    ) -> Result<usize, CC1101Error> {
+        if chunk_len == 0 {
+            return Err(CC1101Error::Config(ConfigError::InvalidPayload));
+        }
+
+        let _tx_guard = TxGuard::new(&self.tx_active);
+        let mut handle = self.get_handle()?;
+
+        let max_chunk_len = Self::max_payload_size(&handle, self.crc)? as usize;
+        if max_chunk_len == 0 {
+            return Err(CC1101Error::Device(DeviceError::PacketSize));
+        }
+
+        Self::set_tx_config_on_device(&handle, tx_config)?;
+
+        Self::write_chunks(
+            &mut handle,
+            source,
+            chunk_len.min(max_chunk_len),
+            self.crc,
+            self.bit_order,
+            |length| self.record_tx(tx_config, length),
+        )
+    }
+
+    /// Split a payload into fragments of at most `max_packet_size` bytes
+    ///
+    /// The final fragment may be shorter. An empty payload produces no fragments.
+    ///
+    /// Returns [`DeviceError::PacketSize`] if `max_packet_size` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// # use cc1101_rust::CC1101;
+    /// let fragments = CC1101::fragment(&[0, 1, 2, 3, 4], 2)?;
+    /// assert_eq!(fragments, vec![&[0, 1][..], &[2, 3][..], &[4][..]]);
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn fragment(data: &[u8], max_packet_size: u32) -> Result<Vec<&[u8]>, CC1101Error> {
+        if max_packet_size == 0 {
+            return Err(CC1101Error::Device(DeviceError::PacketSize));
+        }
+        Ok(data.chunks(max_packet_size as usize).collect())
+    }
+
+    /// Transmit a payload larger than the driver's maximum packet size as multiple packets
+    ///
+    /// The payload is split using [`CC1101::fragment`] with the value of [`CC1101::get_max_packet_size`]. The TX config is set once, then each fragment is transmitted in turn,
+    /// waiting for `delay` between fragments if provided.
+    ///
+    /// If a software CRC has been set with [`CC1101::set_crc`], it is appended to each fragment, and fragments are shortened by 2 bytes to leave room for it.
+    ///
+    /// Returns the number of fragments transmitted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
+    /// # use std::time::Duration;
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// cc1101.transmit_fragmented(&tx_config, &[0x0f; 4096], Some(Duration::from_millis(10)))?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn transmit_fragmented(
+        &self,
+        tx_config: &TXConfig,
+        data: &[u8],
+        delay: Option<Duration>,
+    ) -> Result<usize, CC1101Error> {
+        let _tx_guard = TxGuard::new(&self.tx_active);
+        let mut handle = self.get_handle()?;
+
+        let fragments = Self::fragment(data, Self::max_payload_size(&handle, self.crc)?)?;
+
+        Self::set_tx_config_on_device(&handle, tx_config)?;
+
+        for (i, fragment) in fragments.iter().enumerate() {
+            if i > 0 {
+                if let Some(delay) = delay {
+                    thread::sleep(delay);
+                }
+            }
+            let packet = Self::encode_packet(fragment, self.crc, self.bit_order);
+            Self::write_packet(&mut handle, &packet)?;
+            self.record_tx(tx_config, packet.len());
+        }
+
+        Ok(fragments.len())
+    }
+
+    /// Transmit successive chunks of up to `chunk_len` bytes read from `source` until it is exhausted
+    ///
+    /// `sent` is called with the length of each packet once it has been written, including any CRC.
+    fn write_chunks<W: Write, R: Read>(
+        handle: &mut W,
+        source: &mut R,
+        chunk_len: usize,
+        crc: Option<crc::CrcKind>,
+        bit_order: BitOrder,
+        mut sent: impl FnMut(usize),
+    ) -> Result<usize, CC1101Error> {
+        let mut total = 0;
+        let mut chunk = Vec::with_capacity(chunk_len);
+
+        loop {
+            chunk.clear();
+            source.take(chunk_len as u64).read_to_end(&mut chunk)?;
+
+            if chunk.is_empty() {
+                return Ok(total);
+            }
+
+            let packet = Self::encode_packet(&chunk, crc, bit_order);
+            Self::write_packet(handle, &packet)?;
+            sent(packet.len());
+            total += chunk.len();
+        }
+    }
+
+    /// Append the software CRC, if any, to a payload and apply the bit order
+    fn encode_packet(data: &[u8], crc: Option<crc::CrcKind>, bit_order: BitOrder) -> Cow<'_, [u8]> {
+        match crc {
+            Some(kind) => Cow::Owned(bit_order.encode(&crc::append_crc(kind, data)).into_owned()),
+            None => bit_order.encode(data),
+        }
+    }
+
+    /// Get the maximum payload that fits in a packet once the software CRC, if any, is appended
+    fn max_payload_size(handle: &File, crc: Option<crc::CrcKind>) -> Result<u32, CC1101Error> {
+        let max_packet_size = ioctl::get_max_packet_size(handle)?;
+        match crc {
+            Some(_) => Ok(max_packet_size.saturating_sub(2)),
+            None => Ok(max_packet_size),
+        }
+    }
+
+    /// Set a hook to be called with a [`TxRecord`] for every packet transmitted
+    ///
+    /// The hook is called by all of the transmit methods once each packet has been written to the driver, including each fragment or chunk sent by
+    /// [`CC1101::transmit_fragmented`] and [`CC1101::transmit_from`]. This can be used to keep an audit log of transmissions, for example to demonstrate compliance
+    /// with duty cycle limits. Passing `None` removes the hook.
+    ///
+    /// The hook is called on the transmitting thread, so should return quickly.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let mut cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// cc1101.set_tx_hook(Some(Box::new(|record| println!("{:?}", record))));
+    /// cc1101.transmit(&tx_config, &[0x0f, 0x0f])?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn set_tx_hook(&mut self, hook: Option<TxHook>) {
+        self.tx_hook = hook;
+    }
+
+    /// Pass a record of a transmitted packet to the TX hook, if set
+    fn record_tx(&self, tx_config: &TXConfig, payload_length: usize) {
+        if let Some(hook) = &self.tx_hook {
+            hook(&TxRecord::new(tx_config, payload_length));
+        }
+    }
+
+    /// Write a single packet to the driver for transmission
+    ///
+    /// The driver transmits the whole packet per `write()`, so a short write is reported as [`DeviceError::PacketSize`].
+    fn write_packet<W: Write>(handle: &mut W, data: &[u8]) -> Result<(), CC1101Error> {
+        match handle.write(data) {
+            Ok(length) if length == data.len() => Ok(()),
+            Ok(_) => Err(CC1101Error::Device(DeviceError::PacketSize)),
+            Err(e) => match e.raw_os_error() {
+                Some(libc::EINVAL) => Err(CC1101Error::Device(DeviceError::PacketSize)),
+                Some(libc::ENOMEM) => Err(CC1101Error::Device(DeviceError::OutOfMemory)),
+                Some(libc::EFAULT) => Err(CC1101Error::Device(DeviceError::Copy)),
+                _ => Err(CC1101Error::Device(DeviceError::Unknown)),
+            },
+        }
+    }
+
+    /// Transmit a packet with a software CRC-16 appended
+    ///
+    /// The CRC of `data` is calculated and appended as described in [`CrcKind`](crc::CrcKind), then the packet is transmitted as per [`CC1101::transmit`].
+    /// `kind` is used in place of any CRC set with [`CC1101::set_crc`], so the CRC is only appended once. Received packets can be checked with [`crc::verify_crc`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, crc::CrcKind, config::{TXConfig, Modulation}};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// cc1101.transmit_with_crc(&tx_config, &[0xde, 0xad, 0xbe, 0xef], CrcKind::Ccitt)?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn transmit_with_crc(
+        &self,
+        tx_config: &TXConfig,
+        data: &[u8],
+        kind: crc::CrcKind,
+    ) -> Result<(), CC1101Error> {
+        self.transmit_packet(tx_config, data, Some(kind))
+    }
+
+    /// Transmit a packet provided as a hex string via the radio using the provided configuration
+    ///
+    /// Whitespace and `0x` prefixes are ignored. Returns [`ConfigError::InvalidPayload`] if the string is not valid hex.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// cc1101.transmit_hex(&tx_config, "0x0f0f 0f0f")?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn transmit_hex(&self, tx_config: &TXConfig, hex: &str) -> Result<(), CC1101Error> {
+        let data = parse_hex(hex)?;
+        self.transmit(tx_config, &data)
+    }
+
+    /// Open a file handle to the device
+    fn open(device: &str) -> Result<File, CC1101Error> {
+        let handle = match OpenOptions::new().read(true).write(true).open(device) {
+            Ok(file) => file,
+            Err(e) => match e.raw_os_error() {
+                Some(libc::EBUSY) => return Err(CC1101Error::Device(DeviceError::Busy)),
+                Some(libc::ENOENT) | Some(libc::ENODEV) | Some(libc::ENXIO) => {
+                    return Err(CC1101Error::Device(DeviceError::NoDevice))
+                }
+                _ => return Err(CC1101Error::Device(DeviceError::Unknown)),
+            },
+        };
+
+        Self::check_version(&handle)?;
+
+        Ok(handle)
+    }
+
+    /// Check the driver behind a file handle is the expected version
+    fn check_version(handle: &File) -> Result<(), CC1101Error> {
+        let version = ioctl::get_version(handle)?;
+
+        if version != VERSION {
+            return Err(CC1101Error::Device(DeviceError::VersionMismatch));
+        }
+
+        Ok(())
+    }
+
+    /// Get a handle to the device.
+    ///
+    /// Either re-use the existing handle if in blocking mode, or create a new one.
+    fn get_handle(&self) -> Result<File, CC1101Error> {
+        if let Some(handle) = &self.handle {
+            match handle.try_clone() {
+                Ok(h) => Ok(h),
+                Err(_) => Err(CC1101Error::Device(DeviceError::FileHandleClone)),
+            }
+        } else {
+            Ok(Self::open(&self.device)?)
+        }
+    }
+
+    /// Issue a reset command to the device.
+    ///
+    /// This will clear the received packet buffer and stop receiving. Packet reception can be resumed by calling [`CC1101::receive`].
+    ///
+    /// Returns `true` if RX had been started by this handle and has now been stopped, or `false` if the reset was a no-op for this handle.
+    /// In non-blocking mode, another process may have started or stopped RX in the meantime, which is not reflected in the return value.
+    pub fn reset(&mut self) -> Result<bool, CC1101Error> {
+        ioctl::reset(&self.get_handle()?)?;
+        Ok(self.rx_active.swap(false, Ordering::Relaxed))
+    }
+
+    /// Replace the transmit configuration stored in the driver with [`TXConfig::default`]
+    ///
+    /// The driver keeps the last transmit configuration set by any process sharing the device. This overwrites it with a known default, which is what
+    /// [`CC1101::get_device_tx_config`] will then return. The default configuration has a PATABLE value of `0x00`, the lowest output power.
+    ///
+    /// [`CC1101::transmit`] always sets its own configuration, so this does not affect subsequent transmissions made through this crate.
+    pub fn clear_tx_config(&self) -> Result<(), CC1101Error> {
+        Self::set_tx_config_on_device(&self.get_handle()?, &TXConfig::default())
+    }
+
+    fn set_tx_config_on_device(handle: &File, tx_config: &TXConfig) -> Result<(), CC1101Error> {
+        ioctl::set_tx_conf(handle, tx_config)
+    }
+
+    /// Set the receive configuration.
+    ///
+    /// This will configure the driver for RX with the provided configuration and begin packet reception. Received packets can be read using [`CC1101::receive`].
+    ///
+    /// Returns [`DeviceError::PacketSize`] without changing the configuration if the packet length exceeds the driver's maximum packet size (see [`CC1101::get_max_packet_size`]).
+    ///
+    pub fn set_rx_config(&mut self, rx_config: &RXConfig) -> Result<(), CC1101Error> {
+        let handle = self.get_handle()?;
+        let blocking = self.handle.is_some();
+        Self::set_rx_config_with(
+            &handle,
+            &mut self.rx_config,
+            &self.rx_active,
+            rx_config,
+            blocking,
+        )
+    }
+
+    /// Set the receive configuration using `handle`, updating the saved config and RX state of a [`CC1101`]
+    fn set_rx_config_with(
+        handle: &File,
+        saved_config: &mut Option<RXConfig>,
+        rx_active: &AtomicBool,
+        rx_config: &RXConfig,
+        blocking: bool,
+    ) -> Result<(), CC1101Error> {
+        Self::check_packet_length(handle, rx_config)?;
+        Self::set_rx_config_on_device(
+            handle,
+            saved_config,
+            rx_config,
+            blocking,
+            rx_active.load(Ordering::Relaxed),
+        )?;
+        *saved_config = Some(rx_config.clone());
+        rx_active.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Change the receive frequency, keeping the rest of the current receive configuration
+    ///
+    /// The updated configuration is applied as per [`CC1101::set_rx_config`], so the driver reprograms the radio and its buffer of received packets is reset.
+    ///
+    /// Returns [`DeviceError::NoRXConfig`] if no receive configuration is set. If `frequency` is invalid, the current configuration is left unchanged.
+    pub fn retune(&mut self, frequency: f32) -> Result<(), CC1101Error> {
+        let mut rx_config = match &self.rx_config {
+            Some(rx_config) => rx_config.clone(),
+            None => return Err(CC1101Error::Device(DeviceError::NoRXConfig)),
+        };
+
+        rx_config.get_common_config_mut().set_frequency(frequency)?;
+        self.set_rx_config(&rx_config)
+    }
+
+    /// Change several receive settings at once
+    ///
+    /// Returns a [`CC1101Modify`] which accumulates changes to a copy of the current receive configuration. [`CC1101Modify::apply`] then sets the
+    /// modified configuration with a single call to [`CC1101::set_rx_config`], so the radio is only reconfigured (and the driver's packet buffer reset) once.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{RXConfig, Modulation, CarrierSense}};
+    /// let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+    /// let mut cc1101 = CC1101::new("/dev/cc1101.0.0", Some(rx_config), false)?;
+    ///
+    /// cc1101
+    ///     .modify()
+    ///     .frequency(434.42)
+    ///     .bandwidth(203)
+    ///     .carrier_sense(Some(CarrierSense::Relative(10)))
+    ///     .apply()?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn modify(&mut self) -> CC1101Modify<'_> {
+        let rx_config = self
+            .rx_config
+            .clone()
+            .ok_or(CC1101Error::Device(DeviceError::NoRXConfig));
+
+        CC1101Modify {
+            cc1101: self,
+            rx_config,
+        }
+    }
+
+    /// Set the receive configuration, then wait until the driver reports it as the active configuration
+    ///
+    /// Behaves as [`CC1101::set_rx_config`], then reads back the driver's receive configuration until it matches `rx_config`. This guarantees
+    /// the configuration is live before returning, e.g. before signalling another process sharing the device to begin receiving.
+    ///
+    /// Returns [`DeviceError::ConfigNotApplied`] if the configuration does not match within `timeout`.
+    pub fn set_rx_config_and_wait(
+        &mut self,
+        rx_config: &RXConfig,
+        timeout: Duration,
+    ) -> Result<(), CC1101Error> {
+        self.set_rx_config(rx_config)?;
+
+        let handle = self.get_handle()?;
+        let start = Instant::now();
+        loop {
+            if ioctl::get_rx_conf(&handle)? == *rx_config {
+                return Ok(());
+            }
+            if start.elapsed() >= timeout {
+                return Err(CC1101Error::Device(DeviceError::ConfigNotApplied));
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// Set the receive configuration using the held file handle, without cloning it
+    ///
+    /// Behaves as [`CC1101::set_rx_config`], but avoids duplicating the file descriptor for each call in blocking mode, which is useful when reconfiguring in a tight loop
+    /// (e.g. sweeping frequencies). In non-blocking mode there is no held handle, so this is equivalent to [`CC1101::set_rx_config`].
+    pub fn reconfigure_rx_in_place(&mut self, rx_config: &RXConfig) -> Result<(), CC1101Error> {
+        match &self.handle {
+            Some(handle) => Self::set_rx_config_with(
+                handle,
+                &mut self.rx_config,
+                &self.rx_active,
+                rx_config,
+                true,
+            ),
+            None => self.set_rx_config(rx_config),
+        }
+    }
+
+    /// Check the packet length of a receive configuration against the driver's maximum packet size
+    fn check_packet_length(handle: &File, rx_config: &RXConfig) -> Result<(), CC1101Error> {
+        if rx_config.get_packet_length() > ioctl::get_max_packet_size(handle)? {
+            return Err(CC1101Error::Device(DeviceError::PacketSize));
+        }
+        Ok(())
+    }
+
+    /// Send a receive configuration to the driver, unless it is already set
+    ///
+    /// `rx_active` is whether RX is running with `old_config`. If RX has been stopped by a reset, the configuration is always sent to restart it.
+    fn set_rx_config_on_device(
+        handle: &File,
+        old_config: &Option<RXConfig>,
+        new_config: &RXConfig,
+        blocking: bool,
+        rx_active: bool,
+    ) -> Result<(), CC1101Error> {
+        // Does the new config match the saved config, which is still running
+        let configs_match = match old_config {
+            Some(old_config) => rx_active && old_config == new_config,
+            None => false,
+        };
+
+        if configs_match {
+            // In non-blocking mode, the RX config on the device may become of out sync with the saved config
+            if !blocking {
+                // Get the current config on the device
+                let current_device_config = ioctl::get_rx_conf(handle)?;
+
+                // Update the device if the config on the device and the saved config differ
+                if current_device_config != *new_config {
+                    ioctl::set_rx_conf(handle, new_config)?;
+                }
+            }
+        } else {
+            ioctl::set_rx_conf(handle, new_config)?;
+        }
+        Ok(())
+    }
+
+    /// Get the configured receive config
+    pub fn get_rx_config(&self) -> &Option<RXConfig> {
+        &self.rx_config
+    }
+
+    /// Set the order of bits within each byte of transmitted and received packets
+    ///
+    /// The radio sends and receives the most significant bit of each byte first. With [`BitOrder::LsbFirst`], the bits of each byte are reversed
+    /// in software by the transmit methods before sending, and by the receive methods after reading.
+    pub fn set_bit_order(&mut self, bit_order: BitOrder) {
+        self.bit_order = bit_order;
+    }
+
+    /// Get the order of bits within each byte of transmitted and received packets
+    pub fn get_bit_order(&self) -> BitOrder {
+        self.bit_order
+    }
+
+    /// Set a software CRC-16 to append to every transmitted packet
+    ///
+    /// For protocols that need a CRC the CC1101 hardware CRC cannot produce. The CRC is appended by [`CC1101::transmit`], [`CC1101::transmit_default`], [`CC1101::transmit_hex`],
+    /// and to each fragment or chunk sent by [`CC1101::transmit_fragmented`] and [`CC1101::transmit_from`]. Received packets can be checked with [`crc::verify_crc`].
+    /// Passing `None` disables the CRC.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, crc::CrcKind, config::{TXConfig, Modulation}};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let mut cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// cc1101.set_crc(Some(CrcKind::Ccitt));
+    /// cc1101.transmit(&tx_config, &[0xde, 0xad, 0xbe, 0xef])?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn set_crc(&mut self, crc: Option<crc::CrcKind>) {
+        self.crc = crc;
+    }
+
+    /// Get the software CRC-16 appended to transmitted packets
+    pub fn get_crc(&self) -> Option<crc::CrcKind> {
+        self.crc
+    }
+
+    /// Get the stored transmit config used by [`CC1101::transmit_default`]
+    pub fn get_default_tx_config(&self) -> &Option<TXConfig> {
+        &self.tx_config
+    }
+
+    /// Set the transmit config used by [`CC1101::transmit_default`]
+    ///
+    /// The configuration is only sent to the driver when transmitting.
+    pub fn set_default_tx_config(&mut self, tx_config: Option<TXConfig>) {
+        self.tx_config = tx_config;
+    }
+
+    /// Get the transmit configuration currently set in the driver
+    pub fn get_device_tx_config(&mut self) -> Result<TXConfig, CC1101Error> {
         ioctl::get_tx_conf(&self.get_handle()?)
     }
 
+    /// Check whether the transmit configuration currently set in the driver matches `expected`
+    ///
+    /// Returns `false` if another process sharing the device has set a different transmit configuration since `expected` was last set.
+    /// Note that [`CC1101::transmit`] always sets its own configuration before transmitting.
+    pub fn verify_tx_config(&mut self, expected: &TXConfig) -> Result<bool, CC1101Error> {
+        Ok(self.get_device_tx_config()? == *expected)
+    }
+
     /// Get the receive configuration currently set in the driver
     ///
     /// In non-blocking mode, this may differ from the value returned by [`CC1101::get_rx_config`] if another process has reconfigured the device.
@@ -374,4 +1518,577 @@ impl CC1101 {
     ) -> Result<Registers, CC1101Error> {
         ioctl::get_registers(&self.get_handle()?, registers_type)
     }
+
+    /// Get the registers the driver will write to the CC1101 to transmit with `tx_config`
+    ///
+    /// The mapping from a [`TXConfig`] to registers is performed by the driver, so `tx_config` is temporarily set in the driver and its transmit registers are read back.
+    /// The previous transmit configuration is then restored, even if reading the registers fails, and nothing is transmitted. This can be compared against a known-good SmartRF Studio export to diagnose failed transmits.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation, Registers, RegistersDiff}};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+    ///
+    /// let expected = Registers::from_smartrf(&std::fs::read_to_string("smartrf.txt")?)?;
+    /// let diff = expected.diff(&cc1101.get_tx_registers(&tx_config)?);
+    /// print!("{}", RegistersDiff(&diff));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get_tx_registers(&self, tx_config: &TXConfig) -> Result<Registers, CC1101Error> {
+        let handle = self.get_handle()?;
+
+        let previous_config = ioctl::get_tx_conf(&handle)?;
+        Self::set_tx_config_on_device(&handle, tx_config)?;
+        let registers = ioctl::get_registers(&handle, RegistersType::Tx);
+        let restored = Self::set_tx_config_on_device(&handle, &previous_config);
+
+        let registers = registers?;
+        restored?;
+        Ok(registers)
+    }
+
+    /// Get the device registers and the driver's transmit and receive configuration registers in one call
+    ///
+    /// The returned [`AllRegisters`] can be printed to compare the three side by side.
+    pub fn get_all_registers(&self) -> Result<AllRegisters, CC1101Error> {
+        let handle = self.get_handle()?;
+
+        Ok(AllRegisters {
+            device: ioctl::get_registers(&handle, RegistersType::Device)?,
+            tx: ioctl::get_registers(&handle, RegistersType::Tx)?,
+            rx: ioctl::get_registers(&handle, RegistersType::Rx)?,
+        })
+    }
+
+    /// Capture the receive and transmit configurations currently set in the driver, along with all registers
+    ///
+    /// This can be used to save the state of a device shared with another process before changing it, then put it back with [`CC1101::restore`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use cc1101_rust::{CC1101, config::{TXConfig, Modulation}};
+    /// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+    /// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, true)?;
+    ///
+    /// let snapshot = cc1101.snapshot()?;
+    /// cc1101.transmit(&tx_config, &[0x0f, 0x0f])?;
+    /// cc1101.restore(&snapshot)?;
+    /// # Ok::<(), cc1101_rust::CC1101Error>(())
+    /// ```
+    pub fn snapshot(&self) -> Result<DeviceSnapshot, CC1101Error> {
+        let handle = self.get_handle()?;
+
+        Ok(DeviceSnapshot {
+            rx_active: self.rx_active.load(Ordering::Relaxed),
+            rx_config: ioctl::get_rx_conf(&handle)?,
+            tx_config: ioctl::get_tx_conf(&handle)?,
+            registers: AllRegisters {
+                device: ioctl::get_registers(&handle, RegistersType::Device)?,
+                tx: ioctl::get_registers(&handle, RegistersType::Tx)?,
+                rx: ioctl::get_registers(&handle, RegistersType::Rx)?,
+            },
+        })
+    }
+
+    /// Set the receive and transmit configurations from a [`DeviceSnapshot`] in the driver
+    ///
+    /// The driver does not allow registers to be written directly. It generates them from the configurations, so restoring these restores the registers.
+    /// The restored registers can be compared with those in the snapshot using [`CC1101::get_all_registers`].
+    ///
+    /// If [`DeviceSnapshot::rx_active`] is set, the receive configuration is set, which starts RX. Otherwise, the device is reset to stop RX. Either way,
+    /// the driver's buffer of received packets is reset. The receive configuration is restored first, so if it is rejected the transmit configuration is unchanged.
+    ///
+    /// The receive configuration saved in this [`CC1101`] is not changed, but is marked as no longer set in the driver. A later call to [`CC1101::receive`]
+    /// re-sends it, replacing the restored configuration.
+    pub fn restore(&self, snapshot: &DeviceSnapshot) -> Result<(), CC1101Error> {
+        let handle = self.get_handle()?;
+
+        // Whatever happens, this handle's receive configuration may no longer be the one in the driver
+        self.rx_active.store(false, Ordering::Relaxed);
+
+        if snapshot.rx_active {
+            ioctl::set_rx_conf(&handle, &snapshot.rx_config)?;
+        } else {
+            ioctl::reset(&handle)?;
+        }
+
+        ioctl::set_tx_conf(&handle, &snapshot.tx_config)
+    }
+}
+
+/// Pending changes to the receive configuration of a [`CC1101`], created by [`CC1101::modify`]
+///
+/// Each method changes a copy of the receive configuration. If a change is invalid, later changes are skipped and the error is returned by [`CC1101Modify::apply`].
+/// Dropping a [`CC1101Modify`] without calling [`CC1101Modify::apply`] discards the changes.
+#[must_use = "changes are only made once apply() is called"]
+pub struct CC1101Modify<'a> {
+    cc1101: &'a mut CC1101,
+    rx_config: Result<RXConfig, CC1101Error>,
+}
+
+impl CC1101Modify<'_> {
+    /// Apply a change to the pending configuration, unless an earlier change failed
+    fn update(mut self, change: impl FnOnce(&mut RXConfig) -> Result<(), CC1101Error>) -> Self {
+        if let Ok(rx_config) = &mut self.rx_config {
+            if let Err(e) = change(rx_config) {
+                self.rx_config = Err(e);
+            }
+        }
+        self
+    }
+
+    /// Set the frequency in MHz, as per [`CommonConfig::set_frequency`]
+    pub fn frequency(self, frequency: f32) -> Self {
+        self.update(|rx_config| rx_config.get_common_config_mut().set_frequency(frequency))
+    }
+
+    /// Set the baud rate in kBaud, as per [`CommonConfig::set_baud_rate`]
+    pub fn baud_rate(self, baud_rate: f32) -> Self {
+        self.update(|rx_config| rx_config.get_common_config_mut().set_baud_rate(baud_rate))
+    }
+
+    /// Set the bandwidth in kHz, as per [`RXConfig::set_bandwidth`]
+    pub fn bandwidth(self, bandwidth: u32) -> Self {
+        self.update(|rx_config| rx_config.set_bandwidth(bandwidth))
+    }
+
+    /// Set the carrier sense threshold, as per [`RXConfig::set_carrier_sense`]
+    pub fn carrier_sense(self, carrier_sense: Option<CarrierSense>) -> Self {
+        self.update(|rx_config| rx_config.set_carrier_sense(carrier_sense))
+    }
+
+    /// Set the packet length in bytes, as per [`RXConfig::set_packet_length`]
+    pub fn packet_length(self, packet_length: u32) -> Self {
+        self.update(|rx_config| rx_config.set_packet_length(packet_length))
+    }
+
+    /// Set the modified receive configuration on the device, as per [`CC1101::set_rx_config`]
+    ///
+    /// Returns the first error from any of the changes, or [`DeviceError::NoRXConfig`] if the [`CC1101`] has no receive configuration to modify.
+    pub fn apply(self) -> Result<(), CC1101Error> {
+        let rx_config = self.rx_config?;
+        self.cc1101.set_rx_config(&rx_config)
+    }
+}
+
+/// Marks a transmit as in progress for as long as it is held
+struct TxGuard<'a>(&'a AtomicUsize);
+
+impl<'a> TxGuard<'a> {
+    fn new(tx_active: &'a AtomicUsize) -> TxGuard<'a> {
+        tx_active.fetch_add(1, Ordering::AcqRel);
+        TxGuard(tx_active)
+    }
+}
+
+impl Drop for TxGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Run an operation, retrying it while it fails with [`DeviceError::Busy`]
+///
+/// When sharing a device between processes, an operation can fail with [`DeviceError::Busy`] while another process briefly holds the radio.
+/// This retries `f` up to `retries` times, waiting `backoff` before the first retry and doubling the wait before each subsequent retry.
+/// Any other error is returned immediately, as is the last [`DeviceError::Busy`] once the retries are exhausted.
+///
+/// # Example
+/// ```no_run
+/// # use cc1101_rust::{CC1101, retry_on_busy, config::{TXConfig, Modulation}};
+/// # use std::time::Duration;
+/// let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+/// let cc1101 = CC1101::new("/dev/cc1101.0.0", None, false)?;
+///
+/// retry_on_busy(|| cc1101.transmit(&tx_config, &[0x0f, 0x0f]), 5, Duration::from_millis(10))?;
+/// # Ok::<(), cc1101_rust::CC1101Error>(())
+/// ```
+pub fn retry_on_busy<T, F: FnMut() -> Result<T, CC1101Error>>(
+    mut f: F,
+    retries: u32,
+    backoff: Duration,
+) -> Result<T, CC1101Error> {
+    let mut delay = backoff;
+    let mut attempt = 0;
+
+    loop {
+        match f() {
+            Err(CC1101Error::Device(DeviceError::Busy)) if attempt < retries => {
+                thread::sleep(delay);
+                delay = delay.saturating_mul(2);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Parse a hex string into bytes, ignoring whitespace and `0x` prefixes
+fn parse_hex(hex: &str) -> Result<Vec<u8>, CC1101Error> {
+    let digits: String = hex
+        .split_whitespace()
+        .map(|token| {
+            token
+                .strip_prefix("0x")
+                .or_else(|| token.strip_prefix("0X"))
+                .unwrap_or(token)
+        })
+        .collect();
+
+    digits
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => match ((*high as char).to_digit(16), (*low as char).to_digit(16)) {
+                (Some(high), Some(low)) => Ok((high << 4 | low) as u8),
+                _ => Err(CC1101Error::Config(ConfigError::InvalidPayload)),
+            },
+            _ => Err(CC1101Error::Config(ConfigError::InvalidPayload)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() -> Result<(), CC1101Error> {
+        assert_eq!(parse_hex("")?, Vec::<u8>::new());
+        assert_eq!(parse_hex("0f0F")?, vec![0x0f, 0x0f]);
+        assert_eq!(parse_hex("0x0f0f")?, vec![0x0f, 0x0f]);
+        assert_eq!(
+            parse_hex(" 0xDE 0xad\nbe EF ")?,
+            vec![0xde, 0xad, 0xbe, 0xef]
+        );
+
+        assert!(parse_hex("0").is_err());
+        assert!(parse_hex("0x0g").is_err());
+        assert!(parse_hex("+1").is_err());
+        assert!(parse_hex("é0").is_err());
+
+        Ok(())
+    }
+
+    /// Mock file handle returning a fixed sequence of `read()` results
+    struct MockReader(Vec<std::io::Result<Vec<u8>>>);
+
+    impl Read for MockReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.0.remove(0) {
+                Ok(data) => {
+                    buf[..data.len()].copy_from_slice(&data);
+                    Ok(data.len())
+                }
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_packet() -> Result<(), CC1101Error> {
+        let mut reader = MockReader(vec![
+            Ok(vec![0x01, 0x02, 0x03, 0x04]),
+            Ok(vec![0x01, 0x02]),
+            Ok(vec![]),
+            Err(std::io::Error::from_raw_os_error(libc::ENOMSG)),
+            Err(std::io::Error::from_raw_os_error(libc::EAGAIN)),
+            Err(std::io::Error::from_raw_os_error(libc::EMSGSIZE)),
+        ]);
+
+        assert_eq!(
+            CC1101::read_packet(&mut reader, 4)?,
+            Some(vec![0x01, 0x02, 0x03, 0x04])
+        );
+        assert!(matches!(
+            CC1101::read_packet(&mut reader, 4),
+            Err(CC1101Error::Device(DeviceError::PacketSize))
+        ));
+        assert!(matches!(
+            CC1101::read_packet(&mut reader, 4),
+            Err(CC1101Error::Device(DeviceError::PacketSize))
+        ));
+        assert_eq!(CC1101::read_packet(&mut reader, 4)?, None);
+        assert_eq!(CC1101::read_packet(&mut reader, 4)?, None);
+        assert!(matches!(
+            CC1101::read_packet(&mut reader, 4),
+            Err(CC1101Error::Device(DeviceError::PacketSize))
+        ));
+
+        // Zero length packets are rejected rather than read forever
+        let mut reader = MockReader(vec![Ok(vec![]), Ok(vec![])]);
+        assert!(matches!(
+            CC1101::read_packet(&mut reader, 0),
+            Err(CC1101Error::Device(DeviceError::PacketSize))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_missing_device() {
+        assert!(matches!(
+            CC1101::new("/dev/cc1101-does-not-exist", None, false),
+            Err(CC1101Error::Device(DeviceError::NoDevice))
+        ));
+    }
+
+    #[test]
+    fn test_retry_on_busy() {
+        let mut calls = 0;
+        let result = retry_on_busy(
+            || {
+                calls += 1;
+                match calls {
+                    1 | 2 => Err(CC1101Error::Device(DeviceError::Busy)),
+                    _ => Ok(calls),
+                }
+            },
+            3,
+            Duration::ZERO,
+        );
+        assert_eq!(result.ok(), Some(3));
+
+        let mut calls = 0;
+        let result: Result<(), _> = retry_on_busy(
+            || {
+                calls += 1;
+                Err(CC1101Error::Device(DeviceError::Busy))
+            },
+            2,
+            Duration::ZERO,
+        );
+        assert!(matches!(
+            result,
+            Err(CC1101Error::Device(DeviceError::Busy))
+        ));
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result: Result<(), _> = retry_on_busy(
+            || {
+                calls += 1;
+                Err(CC1101Error::Device(DeviceError::NoDevice))
+            },
+            2,
+            Duration::ZERO,
+        );
+        assert!(matches!(
+            result,
+            Err(CC1101Error::Device(DeviceError::NoDevice))
+        ));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_set_rx_config_after_reset() -> Result<(), CC1101Error> {
+        // ioctls on a file other than the driver fail, showing whether the config was sent
+        let handle = File::open("/dev/null")?;
+        let rx_config = RXConfig::default();
+        let saved_config = Some(rx_config.clone());
+
+        // Same config while RX is running in blocking mode - nothing to send
+        CC1101::set_rx_config_on_device(&handle, &saved_config, &rx_config, true, true)?;
+
+        // Same config after a reset - must be sent to restart RX
+        assert!(
+            CC1101::set_rx_config_on_device(&handle, &saved_config, &rx_config, true, false)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tx_guard() {
+        let tx_active = AtomicUsize::new(0);
+        {
+            let _first = TxGuard::new(&tx_active);
+            {
+                let _second = TxGuard::new(&tx_active);
+                assert_eq!(tx_active.load(Ordering::Acquire), 2);
+            }
+            assert_eq!(tx_active.load(Ordering::Acquire), 1);
+        }
+        assert_eq!(tx_active.load(Ordering::Acquire), 0);
+    }
+
+    #[test]
+    fn test_tx_record() -> Result<(), CC1101Error> {
+        let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 9.9, None, None)?;
+        let record = TxRecord::new(&tx_config, 16);
+        assert_eq!(
+            record.frequency,
+            tx_config.get_common_config().get_frequency()
+        );
+        assert_eq!(record.tx_power, Some(9.9));
+        assert_eq!(record.payload_length, 16);
+        assert_eq!(record.air_time, tx_config.air_time(16));
+
+        let tx_config = TXConfig::new_raw(433.92, Modulation::OOK, 1.0, 0xFF, None, None)?;
+        assert_eq!(TxRecord::new(&tx_config, 16).tx_power, None);
+
+        Ok(())
+    }
+
+    /// Mock file handle recording each `write()` call
+    #[derive(Default)]
+    struct MockWriter(Vec<Vec<u8>>);
+
+    impl Write for MockWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.push(buf.to_vec());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_chunks() -> Result<(), CC1101Error> {
+        let data: Vec<u8> = (0..10).collect();
+        let mut lengths = vec![];
+
+        let mut writer = MockWriter::default();
+        assert_eq!(
+            CC1101::write_chunks(
+                &mut writer,
+                &mut &data[..],
+                4,
+                None,
+                BitOrder::MsbFirst,
+                |length| { lengths.push(length) }
+            )?,
+            10
+        );
+        assert_eq!(lengths, vec![4, 4, 2]);
+        assert_eq!(
+            writer.0,
+            vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]
+        );
+
+        let mut writer = MockWriter::default();
+        assert_eq!(
+            CC1101::write_chunks(
+                &mut writer,
+                &mut &data[..],
+                10,
+                None,
+                BitOrder::MsbFirst,
+                |_| ()
+            )?,
+            10
+        );
+        assert_eq!(writer.0, vec![data.clone()]);
+
+        let mut writer = MockWriter::default();
+        assert_eq!(
+            CC1101::write_chunks(
+                &mut writer,
+                &mut &[][..],
+                4,
+                None,
+                BitOrder::MsbFirst,
+                |_| ()
+            )?,
+            0
+        );
+        assert!(writer.0.is_empty());
+
+        let mut writer = MockWriter::default();
+        assert_eq!(
+            CC1101::write_chunks(
+                &mut writer,
+                &mut &[0x01, 0x80, 0x0f][..],
+                2,
+                None,
+                BitOrder::LsbFirst,
+                |_| ()
+            )?,
+            3
+        );
+        assert_eq!(writer.0, vec![vec![0x80, 0x01], vec![0xf0]]);
+
+        let mut lengths = vec![];
+        let mut writer = MockWriter::default();
+        assert_eq!(
+            CC1101::write_chunks(
+                &mut writer,
+                &mut &b"123456789"[..],
+                5,
+                Some(crc::CrcKind::Ccitt),
+                BitOrder::MsbFirst,
+                |length| { lengths.push(length) }
+            )?,
+            9
+        );
+        assert_eq!(lengths, vec![7, 6]);
+        assert_eq!(
+            writer.0,
+            vec![
+                crc::append_crc(crc::CrcKind::Ccitt, b"12345"),
+                crc::append_crc(crc::CrcKind::Ccitt, b"6789")
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fragment() -> Result<(), CC1101Error> {
+        let data: Vec<u8> = (0..10).collect();
+
+        assert_eq!(
+            CC1101::fragment(&data, 4)?,
+            vec![&data[0..4], &data[4..8], &data[8..10]]
+        );
+        assert_eq!(CC1101::fragment(&data, 10)?, vec![&data[..]]);
+        assert_eq!(CC1101::fragment(&data, 64)?, vec![&data[..]]);
+        assert!(CC1101::fragment(&[], 4)?.is_empty());
+
+        assert!(matches!(
+            CC1101::fragment(&data, 0),
+            Err(CC1101Error::Device(DeviceError::PacketSize))
+        ));
+        assert!(matches!(
+            CC1101::fragment(&[], 0),
+            Err(CC1101Error::Device(DeviceError::PacketSize))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_error_display() {
+        assert_eq!(
+            CC1101Error::Config(ConfigError::InvalidBaudRate {
+                got: 600.0,
+                modulation: Modulation::MSK
+            })
+            .to_string(),
+            "config error: invalid baud rate 600 kBaud for MSK, valid values are 26.0 - 500.0 kBaud"
+        );
+
+        match RXConfig::new(
+            950.0,
+            Modulation::OOK,
+            1.0,
+            64,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) {
+            Err(CC1101Error::Config(ConfigError::InvalidFrequency { got })) => {
+                assert_eq!(got, 950.0)
+            }
+            _ => panic!("expected InvalidFrequency"),
+        }
+    }
 }
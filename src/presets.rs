@@ -0,0 +1,84 @@
+//! Ready-made configurations for common bands and protocols
+//!
+//! These provide a working starting point without choosing each of the [`RXConfig::new`] arguments by hand. The returned configurations can be
+//! adjusted further with the setters on [`RXConfig`] and [`TXConfig`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use cc1101_rust::{CC1101, presets};
+//! let cc1101 = CC1101::new("/dev/cc1101.0.0", Some(presets::ism_433_ook_1kbaud()?), false)?;
+//! # Ok::<(), cc1101_rust::CC1101Error>(())
+//! ```
+
+use crate::config::{CarrierSense, Modulation, RXConfig, TXConfig};
+use crate::CC1101Error;
+
+/// Receive 1 kBaud OOK at 433.92 MHz, as used by many remote controls, doorbells and weather sensors
+///
+/// Reception is triggered by carrier sense (+6 dB above the noise floor) rather than a sync word, so each 64 byte packet contains raw samples
+/// which can be decoded with the functions in [`decode`](crate::decode).
+pub fn ism_433_ook_1kbaud() -> Result<RXConfig, CC1101Error> {
+    RXConfig::new(
+        433.92,
+        Modulation::OOK,
+        1.0,
+        64,
+        None,
+        None,
+        Some(101),
+        Some(CarrierSense::Relative(6)),
+        None,
+        None,
+        None,
+    )
+}
+
+/// Transmit 1 kBaud OOK at 433.92 MHz, matching [`ism_433_ook_1kbaud`]
+///
+/// `tx_power` is in dBm, as per [`TXConfig::set_tx_power`].
+pub fn ism_433_ook_1kbaud_tx(tx_power: f32) -> Result<TXConfig, CC1101Error> {
+    TXConfig::new(433.92, Modulation::OOK, 1.0, tx_power, None, None)
+}
+
+/// Receive LaCrosse IT+ weather sensors - 17.241 kBaud 2-FSK at 868.3 MHz
+///
+/// Packets are 5 bytes following the `0x2DD4` sync word.
+pub fn lacrosse_868_fsk() -> Result<RXConfig, CC1101Error> {
+    RXConfig::new(
+        868.3,
+        Modulation::FSK2,
+        17.241,
+        5,
+        Some(88.867_19),
+        Some(0x2DD4),
+        Some(203),
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() -> Result<(), CC1101Error> {
+        let rx_config = ism_433_ook_1kbaud()?;
+        assert_eq!(rx_config.validate(), Ok(()));
+
+        let tx_config = ism_433_ook_1kbaud_tx(9.9)?;
+        assert_eq!(
+            RXConfig::from_tx(&tx_config, 64)?.get_common_config(),
+            rx_config.get_common_config()
+        );
+
+        let rx_config = lacrosse_868_fsk()?;
+        assert_eq!(rx_config.validate(), Ok(()));
+        assert_eq!(rx_config.get_packet_length(), 5);
+
+        Ok(())
+    }
+}
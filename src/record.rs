@@ -0,0 +1,189 @@
+//! Recording and replay of received packets
+//!
+//! A [`Recorder`] wraps a [`CC1101`] and appends each received packet to a file as a [`Record`], encoded as newline-delimited JSON.
+//! A [`Player`] reads these files back, allowing protocol decoders to be tested offline without a radio.
+//!
+//! Requires the `serde` feature.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use cc1101_rust::{CC1101, config::{RXConfig, Modulation}, record::{Recorder, Player}};
+//! # use std::fs::File;
+//! # use std::io::BufReader;
+//! let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+//! let cc1101 = CC1101::new("/dev/cc1101.0.0", Some(rx_config), false)?;
+//!
+//! let mut recorder = Recorder::new(cc1101, File::create("capture.jsonl")?);
+//! let packets = recorder.receive()?;
+//!
+//! let mut player = Player::new(BufReader::new(File::open("capture.jsonl")?));
+//! assert_eq!(player.receive()?, packets);
+//! # Ok::<(), cc1101_rust::CC1101Error>(())
+//! ```
+
+use crate::config::RXConfig;
+use crate::{CC1101Error, CC1101};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+use std::time::{Instant, SystemTime};
+
+/// A received packet along with the time it was read and the receive configuration in use
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// Time the packet was read from the driver
+    pub timestamp: SystemTime,
+    /// Receive configuration the packet was received with
+    pub rx_config: Option<RXConfig>,
+    /// Packet contents
+    pub packet: Vec<u8>,
+}
+
+/// Receive packets from a [`CC1101`] and write each to a file as a [`Record`]
+pub struct Recorder<W: Write> {
+    cc1101: CC1101,
+    writer: W,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Create a new recorder, writing to `writer`
+    pub fn new(cc1101: CC1101, writer: W) -> Recorder<W> {
+        Recorder { cc1101, writer }
+    }
+
+    /// Receive packets from the radio as per [`CC1101::receive`], recording each before returning them
+    ///
+    /// Each packet is timestamped as it is read from the driver, as per [`CC1101::receive_timestamped`].
+    pub fn receive(&mut self) -> Result<Vec<Vec<u8>>, CC1101Error> {
+        let packets = self.cc1101.receive_timestamped()?;
+
+        // Convert the monotonic read times to wall clock time
+        let (now, now_instant) = (SystemTime::now(), Instant::now());
+
+        for (instant, packet) in &packets {
+            let record = Record {
+                timestamp: now - now_instant.duration_since(*instant),
+                rx_config: self.cc1101.get_rx_config().clone(),
+                packet: packet.clone(),
+            };
+            serde_json::to_writer(&mut self.writer, &record).map_err(std::io::Error::from)?;
+            self.writer.write_all(b"\n")?;
+        }
+        self.writer.flush()?;
+
+        Ok(packets.into_iter().map(|(_, packet)| packet).collect())
+    }
+
+    /// Get a reference to the wrapped radio
+    pub fn get_cc1101(&self) -> &CC1101 {
+        &self.cc1101
+    }
+
+    /// Consume the recorder, returning the wrapped radio and writer
+    pub fn into_inner(self) -> (CC1101, W) {
+        (self.cc1101, self.writer)
+    }
+}
+
+/// Replay packets from a file written by a [`Recorder`]
+///
+/// Iterating over a [`Player`] yields each [`Record`] in the file in order.
+pub struct Player<R: BufRead> {
+    lines: std::io::Lines<R>,
+}
+
+impl<R: BufRead> Player<R> {
+    /// Create a new player, reading from `reader`
+    pub fn new(reader: R) -> Player<R> {
+        Player {
+            lines: reader.lines(),
+        }
+    }
+
+    /// Read all remaining packets, as per [`CC1101::receive`]
+    pub fn receive(&mut self) -> Result<Vec<Vec<u8>>, CC1101Error> {
+        self.map(|record| record.map(|record| record.packet))
+            .collect()
+    }
+}
+
+impl<R: BufRead> Iterator for Player<R> {
+    type Item = Result<Record, CC1101Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            return Some(
+                serde_json::from_str(&line).map_err(|e| CC1101Error::Io(std::io::Error::from(e))),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Modulation;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_player() -> Result<(), CC1101Error> {
+        let rx_config = RXConfig::new(
+            433.92,
+            Modulation::OOK,
+            1.0,
+            4,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        let records = vec![
+            Record {
+                timestamp: SystemTime::UNIX_EPOCH,
+                rx_config: Some(rx_config.clone()),
+                packet: vec![0xde, 0xad, 0xbe, 0xef],
+            },
+            Record {
+                timestamp: SystemTime::now(),
+                rx_config: None,
+                packet: vec![0x01, 0x02, 0x03, 0x04],
+            },
+        ];
+
+        let mut file = vec![];
+        for record in &records {
+            serde_json::to_writer(&mut file, record).map_err(std::io::Error::from)?;
+            file.push(b'\n');
+        }
+
+        let player = Player::new(Cursor::new(&file));
+        assert_eq!(player.collect::<Result<Vec<_>, _>>()?, records);
+
+        let mut player = Player::new(Cursor::new(&file));
+        assert_eq!(
+            player.receive()?,
+            vec![vec![0xde, 0xad, 0xbe, 0xef], vec![0x01, 0x02, 0x03, 0x04]]
+        );
+        assert_eq!(player.receive()?, Vec::<Vec<u8>>::new());
+
+        assert!(Player::new(Cursor::new("not json\n"))
+            .next()
+            .unwrap()
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Conversion between raw RSSI register values and dBm
+//!
+//! The RSSI register holds a signed 8-bit value in units of 0.5 dB. It is converted to an absolute power level using the formula from section 17.3 of the datasheet,
+//! with an RSSI offset that depends on the board. The typical offset is 74 dB.
+//!
+
+/// Convert a raw RSSI register value to dBm
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::rssi::rssi_raw_to_dbm;
+/// assert_eq!(rssi_raw_to_dbm(0xC8, 74), -102.0);
+/// ```
+pub fn rssi_raw_to_dbm(raw: u8, offset: u8) -> f32 {
+    (raw as i8) as f32 / 2.0 - offset as f32
+}
+
+/// Convert a power level in dBm to the nearest raw RSSI register value
+///
+/// Values outside the range of the register are clamped.
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::rssi::dbm_to_rssi_raw;
+/// assert_eq!(dbm_to_rssi_raw(-102.0, 74), 0xC8);
+/// ```
+pub fn dbm_to_rssi_raw(dbm: f32, offset: u8) -> u8 {
+    let raw = ((dbm + offset as f32) * 2.0).round();
+    raw.clamp(i8::MIN as f32, i8::MAX as f32) as i8 as u8
+}
+
+/// Average power levels in dBm
+///
+/// The levels are averaged in linear units (mW), then converted back to dBm, rather than averaging the dB values directly. Returns [`None`] if `levels` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use cc1101_rust::rssi::average_dbm;
+/// // 1 mW and 0 mW average to 0.5 mW, or -3 dBm
+/// assert_eq!(average_dbm(&[0.0, f32::NEG_INFINITY]).map(|dbm| dbm.round()), Some(-3.0));
+/// ```
+pub fn average_dbm(levels: &[f32]) -> Option<f32> {
+    if levels.is_empty() {
+        return None;
+    }
+
+    let total_mw: f32 = levels.iter().map(|dbm| 10_f32.powf(dbm / 10.0)).sum();
+    Some(10.0 * (total_mw / levels.len() as f32).log10())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rssi_raw_to_dbm() {
+        assert_eq!(rssi_raw_to_dbm(0x00, 74), -74.0);
+        assert_eq!(rssi_raw_to_dbm(0x64, 74), -24.0);
+        assert_eq!(rssi_raw_to_dbm(0x7F, 74), -10.5);
+        assert_eq!(rssi_raw_to_dbm(0x80, 74), -138.0);
+        assert_eq!(rssi_raw_to_dbm(0xC8, 74), -102.0);
+        assert_eq!(rssi_raw_to_dbm(0xFF, 74), -74.5);
+        assert_eq!(rssi_raw_to_dbm(0xC8, 79), -107.0);
+    }
+
+    #[test]
+    fn test_dbm_to_rssi_raw() {
+        for raw in 0..=255 {
+            assert_eq!(dbm_to_rssi_raw(rssi_raw_to_dbm(raw, 74), 74), raw);
+        }
+
+        assert_eq!(dbm_to_rssi_raw(-102.2, 74), 0xC8);
+        assert_eq!(dbm_to_rssi_raw(0.0, 74), 0x7F);
+        assert_eq!(dbm_to_rssi_raw(-150.0, 74), 0x80);
+    }
+
+    #[test]
+    fn test_average_dbm() {
+        let approx_eq = |a: Option<f32>, b: f32| (a.unwrap() - b).abs() < 0.001;
+
+        assert_eq!(average_dbm(&[]), None);
+        assert!(approx_eq(average_dbm(&[-80.0]), -80.0));
+        assert!(approx_eq(average_dbm(&[-80.0, -80.0, -80.0]), -80.0));
+
+        // -60 dBm dominates -90 dBm in linear terms, unlike a naive dB average of -75 dBm
+        assert!(approx_eq(average_dbm(&[-60.0, -90.0]), -63.006));
+    }
+}
@@ -0,0 +1,98 @@
+//! Sharing a device between threads
+//!
+//! [`CC1101`] can be shared between processes (see [Device Sharing](crate::CC1101#device-sharing)), but sharing one between threads within a process
+//! needs locking, as most methods take `&mut self` or assume no other call is in progress.
+//!
+//! [`SharedCC1101`] wraps a [`CC1101`] in an `Arc<Mutex<_>>`. Cloning it gives another handle to the same device, and each method locks the device for the
+//! duration of the call.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use cc1101_rust::{CC1101, shared::SharedCC1101, config::{RXConfig, TXConfig, Modulation}};
+//! # use std::{thread, time::Duration};
+//! let rx_config = RXConfig::new(433.92, Modulation::OOK, 1.0, 64, None, None, None, None, None, None, None)?;
+//! let tx_config = TXConfig::new(433.92, Modulation::OOK, 1.0, 0.1, None, None)?;
+//! let cc1101 = SharedCC1101::new(CC1101::new("/dev/cc1101.0.0", Some(rx_config), false)?);
+//!
+//! let receiver = cc1101.clone();
+//! thread::spawn(move || loop {
+//!     for packet in receiver.receive().unwrap() {
+//!         println!("{:x?}", packet);
+//!     }
+//!     thread::sleep(Duration::from_millis(100));
+//! });
+//!
+//! cc1101.transmit(&tx_config, &[0x0f, 0x0f, 0x0f, 0x0f])?;
+//! # Ok::<(), cc1101_rust::CC1101Error>(())
+//! ```
+
+use crate::config::{RXConfig, TXConfig};
+use crate::{CC1101Error, CC1101};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A [`CC1101`] which can be cloned and shared between threads
+///
+/// Calls from different threads are serialised - a thread calling [`SharedCC1101::receive`] waits for any transmit in progress on another thread to complete.
+///
+/// The `blocking` mode of the wrapped [`CC1101`] still determines whether the character device is held open between calls. In blocking mode, other processes
+/// cannot use the device while any thread holds a handle. In non-blocking mode, the device is only opened for the duration of each call, so other processes can
+/// use it between calls, and calls may fail with [`DeviceError::Busy`](crate::DeviceError::Busy) while they do.
+#[derive(Clone)]
+pub struct SharedCC1101 {
+    cc1101: Arc<Mutex<CC1101>>,
+}
+
+impl SharedCC1101 {
+    /// Wrap a [`CC1101`] for sharing between threads
+    pub fn new(cc1101: CC1101) -> SharedCC1101 {
+        SharedCC1101 {
+            cc1101: Arc::new(Mutex::new(cc1101)),
+        }
+    }
+
+    /// Lock the device for exclusive use by the calling thread, to perform operations not covered by the methods of [`SharedCC1101`]
+    ///
+    /// Other threads block until the returned guard is dropped. If another thread panicked while holding the lock, the device is still returned.
+    pub fn lock(&self) -> MutexGuard<'_, CC1101> {
+        self.cc1101
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Receive packets from the radio, as per [`CC1101::receive`]
+    pub fn receive(&self) -> Result<Vec<Vec<u8>>, CC1101Error> {
+        self.lock().receive()
+    }
+
+    /// Transmit a packet via the radio, as per [`CC1101::transmit`]
+    pub fn transmit(&self, tx_config: &TXConfig, data: &[u8]) -> Result<(), CC1101Error> {
+        self.lock().transmit(tx_config, data)
+    }
+
+    /// Set the receive configuration, as per [`CC1101::set_rx_config`]
+    pub fn set_rx_config(&self, rx_config: &RXConfig) -> Result<(), CC1101Error> {
+        self.lock().set_rx_config(rx_config)
+    }
+
+    /// Get the current RSSI in dBm, as per [`CC1101::get_rssi_dbm`]
+    pub fn get_rssi_dbm(&self) -> Result<f32, CC1101Error> {
+        self.lock().get_rssi_dbm()
+    }
+
+    /// Reset the radio, as per [`CC1101::reset`]
+    pub fn reset(&self) -> Result<bool, CC1101Error> {
+        self.lock().reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync + Clone>() {}
+        assert_send_sync::<SharedCC1101>();
+    }
+}